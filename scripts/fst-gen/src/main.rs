@@ -0,0 +1,32 @@
+//! Writes the names and aliases of every code point, as listed by the
+//! generated tables, into the FST map embedded by the `fst` feature.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+use unicode_charname::CharName;
+
+fn main() {
+    let mut names = BTreeMap::new();
+    for v in 0..=0x10_FFFFu32 {
+        if let Some(name) = v.property_name() {
+            names.insert(name.to_string(), u64::from(v));
+        }
+    }
+    // names take precedence over aliases, as in `lookup`.
+    for v in 0..=0x10_FFFFu32 {
+        for &(_, alias) in v.name_aliases() {
+            names.entry(alias.to_string()).or_insert(u64::from(v));
+        }
+    }
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../src/names.fst");
+    let file = File::create(&path).expect("can't create names.fst");
+    let mut builder = fst::MapBuilder::new(BufWriter::new(file)).expect("can't start the FST");
+    builder
+        .extend_iter(names)
+        .expect("names are sorted and unique");
+    builder.finish().expect("can't write names.fst");
+}
@@ -12,9 +12,17 @@
 
 # This script uses the following Unicode tables:
 # - UnicodeData.txt
+# - NameAliases.txt
+# - Blocks.txt
+# - DerivedAge.txt
+# - NamedSequences.txt
+# - emoji-test.txt (for the emoji-names feature)
 #
 # Since this should not require frequent updates, we just store this
 # out-of-line and check the unicode.rs file into git.
+#
+# src/names.fst, embedded by the fst feature, is then built from the new
+# tables by scripts/fst-gen; `test_fst_matches_tables` fails until it is.
 
 import fileinput
 import re
@@ -84,9 +92,100 @@ def load_names(f, interestingprops):
     return (normal_names, special_names)
 
 
+def load_format_chars(f):
+    fetch(f)
+    format_chars = []
+    re1 = re.compile(r"^([0-9A-F]+);[^;]*;(Cf|Zl|Zp);.*$")
+
+    for line in fileinput.input(os.path.basename(f)):
+        m = re1.match(line)
+        if m:
+            format_chars.append(int(m.group(1), 16))
+    return format_chars
+
+
+def load_unicode_1_names(f):
+    fetch(f)
+    unicode_1_names = []
+    re1 = re.compile(r"^([0-9A-F]+);(?:[^;]*;){9}([^;]+);.*$")
+
+    for line in fileinput.input(os.path.basename(f)):
+        m = re1.match(line)
+        if m:
+            unicode_1_names.append((int(m.group(1), 16), m.group(2)))
+    return unicode_1_names
+
+
+def load_blocks(f):
+    fetch(f)
+    blocks = []
+    re1 = re.compile(r"^([0-9A-F]+)\.\.([0-9A-F]+); (.+)$")
+
+    for line in fileinput.input(os.path.basename(f)):
+        m = re1.match(line.strip())
+        if m:
+            blocks.append((int(m.group(1), 16), int(m.group(2), 16), m.group(3)))
+    return blocks
+
+
+def load_ages(f):
+    fetch(f)
+    ages = []
+    re1 = re.compile(r"^([0-9A-F]+)(?:\.\.([0-9A-F]+))?\s*; (\d+)\.(\d+)\b.*$")
+
+    for line in fileinput.input(os.path.basename(f)):
+        m = re1.match(line.strip())
+        if m:
+            first = int(m.group(1), 16)
+            last = int(m.group(2), 16) if m.group(2) else first
+            ages.append((first, last, int(m.group(3)), int(m.group(4))))
+    return ages
+
+
+def load_named_sequences(f):
+    fetch(f)
+    sequences = []
+    re1 = re.compile(r"^([A-Z0-9 -]+);\s*([0-9A-F ]+)$")
+
+    for line in fileinput.input(os.path.basename(f)):
+        m = re1.match(line.strip())
+        if m:
+            chars = [int(ch, 16) for ch in m.group(2).split()]
+            sequences.append((m.group(1), chars))
+    return sequences
+
+
+def load_emoji_names(f):
+    fetch(f)
+    emoji_names = []
+    re1 = re.compile(
+        r"^([0-9A-F ]+?)\s*;\s*(fully-qualified|minimally-qualified|unqualified|component)"
+        r"\s*#\s*\S+\s+E[0-9]+\.[0-9]+\s+(.+)$")
+
+    for line in fileinput.input(os.path.basename(f)):
+        m = re1.match(line.strip())
+        if m:
+            chars = [int(ch, 16) for ch in m.group(1).split()]
+            emoji_names.append((chars, m.group(3)))
+    return emoji_names
+
+
+def load_name_aliases(f):
+    fetch(f)
+    aliases = {}
+    re1 = re.compile(r"^([0-9A-F]+);([^;]+);([a-z]+)$")
+
+    for line in fileinput.input(os.path.basename(f)):
+        m = re1.match(line.strip())
+        if m:
+            ch = int(m.group(1), 16)
+            aliases.setdefault(ch, []).append((m.group(3), m.group(2)))
+    return aliases
+
+
 SPACE_SYMBOL = ' '
 CODEPOINT_SYMBOL = '@'
-SPECIAL_SYMBOLS = ['-', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9']
+SPECIAL_SYMBOLS = ['-']
 
 
 def tokenize(str, codepoint):
@@ -130,6 +229,11 @@ class WordIndex:
         codepoint_symbol_idx = word_map[CODEPOINT_SYMBOL]
         special_map[codepoint_symbol_idx] = True
         special_list.append(codepoint_symbol_idx)
+        # spaces are only encoded next to special words, like in "LETTER -A",
+        # and must not get another space inserted next to them when decoding.
+        space_symbol_idx = word_map[SPACE_SYMBOL]
+        special_map[space_symbol_idx] = True
+        special_list.append(space_symbol_idx)
         special_list.sort()
         self.word_list = word_list
         self.word_map = word_map
@@ -227,7 +331,10 @@ def create_special_groups(special_names):
             label = m3.group(1)
             last_repeat_item_idx = item_idx
             try_item_idx = item_idx + 1
-            while try_item_idx < item_count and special_names[try_item_idx][1] == item_text:
+            # only merge runs of consecutive code points.
+            while try_item_idx < item_count and special_names[try_item_idx][1] == item_text \
+                    and int(special_names[try_item_idx][0], 16) == \
+                    int(special_names[last_repeat_item_idx][0], 16) + 1:
                 last_repeat_item_idx = try_item_idx
                 try_item_idx += 1
             result.append((int(special_names[item_idx][0], 16), int(
@@ -241,7 +348,7 @@ def create_special_groups(special_names):
 
 def write_enumeration_char_names(rf, encoded_groups):
     rf.write("""
-pub const ENUMERATION_CHAR_NAMES: &'static [(u32, u32, &'static [u16], &'static [u32])] = &[
+pub const ENUMERATION_CHAR_NAMES: &[(u32, u32, &[u16], &[u32])] = &[
 """)
     for (first, last, group_buffer, pos_buffer) in encoded_groups:
         rf.write("\t(%d, %d, &%s, &%s),\n" %
@@ -251,18 +358,42 @@ pub const ENUMERATION_CHAR_NAMES: &'static [(u32, u32, &'static [u16], &'static
 
 
 def write_special_groups(rf, special_groups):
+    for (first, last, groupname) in special_groups:
+        if any(is_noncharacter(ch) for ch in range(first, last + 1)):
+            raise Exception("Noncharacter in group! [%s]" % groupname)
     rf.write("""
 #[allow(non_camel_case_types)]
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub enum SpecialGroup {
 """)
+    group_variants = []
     for ((_, _, groupname)) in special_groups:
         group_variant = groupname.replace(' ', '')
+        if group_variant not in group_variants:
+            group_variants.append(group_variant)
+    for group_variant in group_variants:
         rf.write("\t%s,\n" % group_variant)
     rf.write("""}
+
+impl SpecialGroup {
+    /// The label of the group in UnicodeData.txt, like "CJK Ideograph".
+    #[cfg(test)]
+    pub fn label(self) -> &'static str {
+        match self {
+""")
+    group_labels = []
+    for ((_, _, groupname)) in special_groups:
+        if groupname not in group_labels:
+            group_labels.append(groupname)
+    for groupname in group_labels:
+        rf.write("            SpecialGroup::%s => \"%s\",\n" %
+                 (groupname.replace(' ', ''), groupname))
+    rf.write("""        }
+    }
+}
 """)
     rf.write("""
-pub const SPECIAL_GROUPS: &'static [(u32, u32, SpecialGroup)] = &[
+pub const SPECIAL_GROUPS: &[(u32, u32, SpecialGroup)] = &[
 """)
     for (idx, (first, last, groupname)) in enumerate(special_groups):
         if idx % 2 == 0:
@@ -270,7 +401,7 @@ pub const SPECIAL_GROUPS: &'static [(u32, u32, SpecialGroup)] = &[
         group_variant = groupname.replace(' ', '')
         group_variant_full = "SpecialGroup::" + group_variant
         rf.write("(%d, %d, %s), " % (first, last, group_variant_full))
-        if (idx + 1) % 2 == 0:
+        if (idx + 1) % 2 == 0 or idx + 1 == len(special_groups):
             rf.write('\n')
     rf.write("""];
 
@@ -294,9 +425,407 @@ pub fn find_in_special_groups(ch: u32) -> Option<SpecialGroup> {
 """)
 
 
+def write_format_chars(rf, format_chars):
+    rf.write("""
+/// Ranges of the characters with General_Category Cf, Zl or Zp.
+pub const FORMAT_CHARS: &[(u32, u32)] = &[
+""")
+    intervals = create_intervals(format_chars)
+    for (idx, (first, last)) in enumerate(intervals):
+        if idx % 4 == 0:
+            rf.write("\t")
+        rf.write("(%d, %d), " % (first, last))
+        if (idx + 1) % 4 == 0 or idx + 1 == len(intervals):
+            rf.write('\n')
+    rf.write("""];
+
+pub fn is_format_char(ch: u32) -> bool {
+    FORMAT_CHARS
+        .binary_search_by(|record| {
+            use std::cmp::Ordering;
+            if record.1 < ch {
+                Ordering::Less
+            } else if record.0 > ch {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+""")
+
+
+def write_name_aliases(rf, aliases):
+    rf.write("""
+use crate::AliasKind;
+
+pub const NAME_ALIASES: &[(u32, &[(AliasKind, &str)])] = &[
+""")
+    for ch in sorted(aliases):
+        entries = ", ".join('(AliasKind::%s, "%s")' % (kind.capitalize(), alias)
+                            for (kind, alias) in aliases[ch])
+        rf.write("\t(%d, &[%s]),\n" % (ch, entries))
+    rf.write("""];
+
+pub fn find_name_aliases(ch: u32) -> &'static [(AliasKind, &'static str)] {
+    match NAME_ALIASES.binary_search_by_key(&ch, |record| record.0) {
+        Ok(record_idx) => NAME_ALIASES[record_idx].1,
+        Err(_) => &[],
+    }
+}
+
+/// The entries of `NAME_ALIASES`, sorted by alias.
+pub const NAME_ALIASES_BY_ALIAS: &[(&str, AliasKind, u32)] = &[
+""")
+    by_alias = sorted((alias, kind, ch)
+                      for ch in aliases for (kind, alias) in aliases[ch])
+    for (prev, entry) in zip(by_alias, by_alias[1:]):
+        if prev[0] == entry[0]:
+            raise Exception("alias %s is ambiguous" % entry[0])
+    for (alias, kind, ch) in by_alias:
+        rf.write('\t("%s", AliasKind::%s, %d),\n' % (alias, kind.capitalize(), ch))
+    rf.write("""];
+
+pub fn find_alias(alias: &str) -> Option<(AliasKind, u32)> {
+    let record_idx = NAME_ALIASES_BY_ALIAS
+        .binary_search_by_key(&alias, |record| record.0)
+        .ok()?;
+    let (_, kind, ch) = NAME_ALIASES_BY_ALIAS[record_idx];
+    Some((kind, ch))
+}
+
+/// The indices of `NAME_ALIASES_BY_ALIAS`, sorted by the UAX44-LM2 loose
+/// matching key of their aliases.
+pub const NAME_ALIASES_BY_LOOSE_ALIAS: &[u16] = &[
+""")
+    by_loose_alias = sorted(range(len(by_alias)),
+                            key=lambda idx: loose_key(by_alias[idx][0]))
+    for (prev, idx) in zip(by_loose_alias, by_loose_alias[1:]):
+        if loose_key(by_alias[prev][0]) == loose_key(by_alias[idx][0]):
+            raise Exception("%s and %s match loosely" %
+                            (by_alias[prev][0], by_alias[idx][0]))
+    for (n, idx) in enumerate(by_loose_alias):
+        if n % 8 == 0:
+            rf.write("\t")
+        rf.write("%d, " % idx)
+        if (n + 1) % 8 == 0 or n + 1 == len(by_loose_alias):
+            rf.write('\n')
+    rf.write("""];
+""")
+
+
+def write_unicode_1_names(rf, unicode_1_names):
+    rf.write("""
+pub const UNICODE_1_NAMES: &[(u32, &str)] = &[
+""")
+    for (ch, name) in sorted(unicode_1_names):
+        rf.write("\t(%d, \"%s\"),\n" % (ch, name))
+    rf.write("""];
+
+pub fn find_unicode_1_name(ch: u32) -> Option<&'static str> {
+    let record_idx = UNICODE_1_NAMES
+        .binary_search_by_key(&ch, |record| record.0)
+        .ok()?;
+    Some(UNICODE_1_NAMES[record_idx].1)
+}
+
+/// The indices of `UNICODE_1_NAMES`, sorted by name.
+pub const UNICODE_1_NAMES_BY_NAME: &[u16] = &[
+""")
+    records = sorted(unicode_1_names)
+    by_name = sorted(range(len(records)), key=lambda idx: records[idx][1])
+    for (prev, idx) in zip(by_name, by_name[1:]):
+        if records[prev][1] == records[idx][1]:
+            raise Exception("Unicode 1.0 name %s is ambiguous" % records[idx][1])
+    for (n, idx) in enumerate(by_name):
+        if n % 8 == 0:
+            rf.write("\t")
+        rf.write("%d, " % idx)
+        if (n + 1) % 8 == 0 or n + 1 == len(by_name):
+            rf.write('\n')
+    rf.write("""];
+
+pub fn find_by_unicode_1_name(name: &str) -> Option<u32> {
+    let record_idx = UNICODE_1_NAMES_BY_NAME
+        .binary_search_by_key(&name, |&idx| UNICODE_1_NAMES[usize::from(idx)].1)
+        .ok()?;
+    Some(UNICODE_1_NAMES[usize::from(UNICODE_1_NAMES_BY_NAME[record_idx])].0)
+}
+""")
+
+
+def write_blocks(rf, blocks):
+    rf.write("""
+pub const BLOCKS: &[(u32, u32, &str)] = &[
+""")
+    for (first, last, name) in sorted(blocks):
+        rf.write("\t(%d, %d, \"%s\"),\n" % (first, last, name))
+    rf.write("""];
+
+pub fn find_block(ch: u32) -> Option<(u32, u32, &'static str)> {
+    let record_idx = BLOCKS
+        .binary_search_by(|record| {
+            use std::cmp::Ordering;
+            if record.1 < ch {
+                Ordering::Less
+            } else if record.0 > ch {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()?;
+    Some(BLOCKS[record_idx])
+}
+""")
+
+
+def write_ages(rf, ages):
+    rf.write("""
+pub const AGES: &[(u32, u32, (u8, u8))] = &[
+""")
+    for (idx, (first, last, major, minor)) in enumerate(sorted(ages)):
+        if idx % 4 == 0:
+            rf.write("\t")
+        rf.write("(%d, %d, (%d, %d)), " % (first, last, major, minor))
+        if (idx + 1) % 4 == 0 or idx + 1 == len(ages):
+            rf.write('\n')
+    rf.write("""];
+
+pub fn find_age(ch: u32) -> Option<(u8, u8)> {
+    let record_idx = AGES
+        .binary_search_by(|record| {
+            use std::cmp::Ordering;
+            if record.1 < ch {
+                Ordering::Less
+            } else if record.0 > ch {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()?;
+    Some(AGES[record_idx].2)
+}
+""")
+
+
+def write_named_sequences(rf, sequences):
+    rf.write("""
+pub const NAMED_SEQUENCES: &[(&str, &str)] = &[
+""")
+    for (name, chars) in sorted(sequences):
+        rf.write("\t(\"%s\", \"%s\"),\n" %
+                 (name, "".join("\\u{%X}" % ch for ch in chars)))
+    rf.write("""];
+
+pub fn find_named_sequence(name: &str) -> Option<&'static str> {
+    let record_idx = NAMED_SEQUENCES
+        .binary_search_by_key(&name, |record| record.0)
+        .ok()?;
+    Some(NAMED_SEQUENCES[record_idx].1)
+}
+
+/// The indices of `NAMED_SEQUENCES`, sorted by the UAX44-LM2 loose matching
+/// key of their names.
+pub const NAMED_SEQUENCES_BY_LOOSE_NAME: &[u16] = &[
+""")
+    names = [name for (name, _) in sorted(sequences)]
+    by_loose_name = sorted(range(len(names)), key=lambda idx: loose_key(names[idx]))
+    for (prev, idx) in zip(by_loose_name, by_loose_name[1:]):
+        if loose_key(names[prev]) == loose_key(names[idx]):
+            raise Exception("%s and %s match loosely" % (names[prev], names[idx]))
+    for (n, idx) in enumerate(by_loose_name):
+        if n % 8 == 0:
+            rf.write("\t")
+        rf.write("%d, " % idx)
+        if (n + 1) % 8 == 0 or n + 1 == len(by_loose_name):
+            rf.write('\n')
+    rf.write("""];
+""")
+
+
+def write_emoji_names(rf, emoji_names):
+    # sorted like Rust's `str`, by UTF-8 bytes.
+    def sort_key(record):
+        return "".join(chr(ch) for ch in record[0]).encode("utf-8")
+
+    rf.write("""
+/// The version of the emoji data that the `emoji-names` feature is based on.
+#[cfg(feature = "emoji-names")]
+pub const EMOJI_VERSION: (u64, u64, u64) = (%s, %s, %s);
+
+#[cfg(feature = "emoji-names")]
+pub const EMOJI_NAMES: &[(&str, &str)] = &[
+""" % UNICODE_VERSION)
+    for (chars, name) in sorted(emoji_names, key=sort_key):
+        rf.write("\t(\"%s\", \"%s\"),\n" %
+                 ("".join("\\u{%X}" % ch for ch in chars),
+                  name.replace("\\", "\\\\").replace("\"", "\\\"")))
+    rf.write("""];
+
+#[cfg(feature = "emoji-names")]
+pub fn find_emoji_name(s: &str) -> Option<&'static str> {
+    let record_idx = EMOJI_NAMES
+        .binary_search_by_key(&s, |record| record.0)
+        .ok()?;
+    Some(EMOJI_NAMES[record_idx].1)
+}
+""")
+
+
+def write_names_by_name(rf, normal_names):
+    rf.write("""
+/// The code points of `ENUMERATION_CHAR_NAMES`, sorted by name.
+pub const NAMES_BY_NAME: &[u32] = &[
+""")
+    by_name = sorted(normal_names.keys(), key=lambda ch: normal_names[ch])
+    for (idx, ch) in enumerate(by_name):
+        if idx % 8 == 0:
+            rf.write("\t")
+        rf.write("%d, " % int(ch, 16))
+        if (idx + 1) % 8 == 0 or idx + 1 == len(by_name):
+            rf.write('\n')
+    rf.write("""];
+""")
+
+
+def fnv1a(name):
+    h = 0x811c9dc5
+    for b in name.encode("ascii"):
+        h = ((h ^ b) * 0x01000193) & 0xffffffff
+    return h
+
+
+def phf_hash(key, salt, n):
+    # the hash of the minimal perfect hash tables of unicode-normalization,
+    # over the FNV-1a hash of the name.
+    y = ((key + salt) * 2654435769) & 0xffffffff
+    y ^= (key * 0x31415926) & 0xffffffff
+    return (y * n) >> 32
+
+
+def minimal_perfect_hash(keys):
+    n = len(keys)
+    buckets = [[] for _ in range(n)]
+    for key in keys:
+        buckets[phf_hash(key, 0, n)].append(key)
+    claimed = [False] * n
+    salts = [0] * n
+    slots = [None] * n
+    for h in sorted(range(n), key=lambda h: -len(buckets[h])):
+        if not buckets[h]:
+            break
+        for salt in range(1, 0x10000):
+            rehashes = [phf_hash(key, salt, n) for key in buckets[h]]
+            if len(set(rehashes)) == len(rehashes) and \
+                    not any(claimed[idx] for idx in rehashes):
+                break
+        else:
+            raise Exception("no salt for bucket %d" % h)
+        salts[h] = salt
+        for (key, idx) in zip(buckets[h], rehashes):
+            claimed[idx] = True
+            slots[idx] = key
+    return (salts, slots)
+
+
+def write_names_phf(rf, normal_names):
+    rf.write("""
+/// The salts of the minimal perfect hash function over the names of
+/// `ENUMERATION_CHAR_NAMES`, see `phf_name_candidate`.
+#[cfg(feature = "phf")]
+pub const PHF_NAME_SALTS: &[u16] = &[
+""")
+    by_hash = dict((fnv1a(name), int(ch, 16))
+                   for (ch, name) in normal_names.items())
+    if len(by_hash) != len(normal_names):
+        raise Exception("the FNV-1a hashes of the names collide")
+    (salts, slots) = minimal_perfect_hash(list(by_hash))
+    for (idx, salt) in enumerate(salts):
+        if idx % 16 == 0:
+            rf.write("\t")
+        rf.write("%d, " % salt)
+        if (idx + 1) % 16 == 0 or idx + 1 == len(salts):
+            rf.write('\n')
+    rf.write("""];
+
+/// The code points of `ENUMERATION_CHAR_NAMES`, in the slots given by the
+/// hashes of their names.
+#[cfg(feature = "phf")]
+pub const PHF_NAME_CODE_POINTS: &[u32] = &[
+""")
+    for (idx, key) in enumerate(slots):
+        if idx % 8 == 0:
+            rf.write("\t")
+        rf.write("%d, " % by_hash[key])
+        if (idx + 1) % 8 == 0 or idx + 1 == len(slots):
+            rf.write('\n')
+    rf.write("""];
+
+#[cfg(feature = "phf")]
+fn phf_hash(key: u32, salt: u32, n: usize) -> usize {
+    let y = key.wrapping_add(salt).wrapping_mul(2654435769);
+    let y = y ^ key.wrapping_mul(0x31415926);
+    ((y as u64 * n as u64) >> 32) as usize
+}
+
+/// Returns the only code point of `ENUMERATION_CHAR_NAMES` that may be
+/// named `name`, which the caller has to check.
+#[cfg(feature = "phf")]
+pub fn phf_name_candidate(name: &str) -> u32 {
+    let key = name.bytes().fold(0x811c9dc5u32, |h, b| {
+        (h ^ u32::from(b)).wrapping_mul(0x01000193)
+    });
+    let n = PHF_NAME_CODE_POINTS.len();
+    let salt = PHF_NAME_SALTS[phf_hash(key, 0, n)];
+    PHF_NAME_CODE_POINTS[phf_hash(key, u32::from(salt), n)]
+}
+""")
+
+
+def loose_key(name):
+    # UAX44-LM2, as in `LooseChars`: drop spaces, underscores and medial
+    # hyphens, except in U+1180 HANGUL JUNGSEONG O-E, and fold case.
+    keep_medial_hyphens = name == "HANGUL JUNGSEONG O-E"
+    key = []
+    for (idx, c) in enumerate(name):
+        if c in " _":
+            continue
+        if c == "-" and not keep_medial_hyphens:
+            prev = name[idx - 1] if idx > 0 else ""
+            next = name[idx + 1] if idx + 1 < len(name) else ""
+            if prev.isalnum() and next.isalnum():
+                continue
+        key.append(c.lower())
+    return "".join(key)
+
+
+def write_names_by_loose_name(rf, normal_names):
+    rf.write("""
+/// The code points of `ENUMERATION_CHAR_NAMES`, sorted by the UAX44-LM2
+/// loose matching key of their names.
+pub const NAMES_BY_LOOSE_NAME: &[u32] = &[
+""")
+    by_loose_name = sorted(normal_names.keys(),
+                           key=lambda ch: loose_key(normal_names[ch]))
+    for (prev, ch) in zip(by_loose_name, by_loose_name[1:]):
+        if loose_key(normal_names[prev]) == loose_key(normal_names[ch]):
+            raise Exception("%s and %s match loosely" % (prev, ch))
+    for (idx, ch) in enumerate(by_loose_name):
+        if idx % 8 == 0:
+            rf.write("\t")
+        rf.write("%d, " % int(ch, 16))
+        if (idx + 1) % 8 == 0 or idx + 1 == len(by_loose_name):
+            rf.write('\n')
+    rf.write("""];
+""")
+
+
 def write_word_table(rf, word_table):
     rf.write("""
-pub const ENUMERATION_WORD_TABLE: &'static [&'static str] = &[
+pub const ENUMERATION_WORD_TABLE: &[&str] = &[
 """)
     for (idx, word) in enumerate(word_table):
         if idx % 8 == 0:
@@ -335,18 +864,77 @@ pub const WORD_TABLE_INDEX_SPACE: u16 = %d;
     rf.write("""
 pub const WORD_TABLE_INDEX_CODEPOINT: u16 = %d;
 """ % word_index.word_map[CODEPOINT_SYMBOL])
+    rf.write("""
+pub const WORD_TABLE_INDEX_HYPHEN: u16 = %d;
+""" % word_index.word_map['-'])
 
     special_intervals = create_intervals(word_index.special_list)
     rf.write("""
 pub fn is_special_word_index(v: u16) -> bool {
-    match v {
-""")
-    for (first, last) in special_intervals:
-        rf.write("\t\t%d..=%d => true,\n" % (first, last))
-    rf.write("""\t\t_ => false,
-    }
+    matches!(v, %s)
 }
-""")
+""" % " | ".join("%d..=%d" % (first, last) for (first, last) in special_intervals))
+
+
+# Mirrors the jamo short names in src/jamo.rs.
+JAMO_L_TABLE = ["G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "",
+                "J", "JJ", "C", "K", "T", "P", "H"]
+JAMO_V_TABLE = ["A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE",
+                "OE", "YO", "U", "WEO", "WE", "WI", "YU", "EU", "YI", "I"]
+JAMO_T_TABLE = ["", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB",
+                "LS", "LT", "LP", "LH", "M", "B", "BS", "S", "SS", "NG", "J", "C",
+                "K", "T", "P", "H"]
+
+
+def special_group_name(ch, groupname):
+    if groupname == "Hangul Syllable":
+        s_index = ch - 0xAC00
+        l_index = s_index // (21 * 28)
+        v_index = (s_index % (21 * 28)) // 28
+        t_index = s_index % 28
+        return "HANGUL SYLLABLE " + JAMO_L_TABLE[l_index] + \
+            JAMO_V_TABLE[v_index] + JAMO_T_TABLE[t_index]
+    if groupname.startswith("CJK Ideograph"):
+        return "CJK UNIFIED IDEOGRAPH-%04X" % ch
+    if groupname.startswith("Tangut Ideograph"):
+        return "TANGUT IDEOGRAPH-%04X" % ch
+    if groupname == "control":
+        return "<control-%04X>" % ch
+    if groupname.endswith("Surrogate"):
+        return "<surrogate-%04X>" % ch
+    if groupname.endswith("Private Use"):
+        return "<private-use-%04X>" % ch
+    raise Exception("Unexpected special group: %s" % groupname)
+
+
+def is_noncharacter(ch):
+    return 0xFDD0 <= ch <= 0xFDEF or (ch & 0xFFFE) == 0xFFFE
+
+
+def write_name_max_len(rf, normal_names, special_groups):
+    longest = (0, None)
+    for ch in range(0x110000):
+        ch_str = "%04X" % ch
+        if ch_str in normal_names:
+            name = normal_names[ch_str]
+        else:
+            group = [groupname for (first, last, groupname)
+                     in special_groups if first <= ch <= last]
+            if group:
+                name = special_group_name(ch, group[0])
+            elif is_noncharacter(ch):
+                name = "<noncharacter-%04X>" % ch
+            else:
+                name = "<reserved-%04X>" % ch
+        if len(name) > longest[0]:
+            longest = (len(name), ch)
+    rf.write("""
+/// The length in bytes of the longest name returned by `char_name`,
+/// see [`longest_name`](crate::longest_name).
+pub const NAME_MAX_LEN: usize = %d;
+
+pub const NAME_MAX_LEN_CODEPOINT: u32 = %d;
+""" % longest)
 
 
 if __name__ == "__main__":
@@ -369,5 +957,16 @@ pub const UNICODE_VERSION: (u64, u64, u64) = (%s, %s, %s);
 
         write_enumeration_char_names(rf, normal_encoded_groups)
         write_special_groups(rf, special_groups)
+        write_format_chars(rf, load_format_chars("UnicodeData.txt"))
+        write_name_aliases(rf, load_name_aliases("NameAliases.txt"))
+        write_unicode_1_names(rf, load_unicode_1_names("UnicodeData.txt"))
+        write_blocks(rf, load_blocks("Blocks.txt"))
+        write_ages(rf, load_ages("DerivedAge.txt"))
+        write_named_sequences(rf, load_named_sequences("NamedSequences.txt"))
+        write_emoji_names(rf, load_emoji_names("emoji-test.txt"))
+        write_names_by_name(rf, normal_names)
+        write_names_by_loose_name(rf, normal_names)
+        write_names_phf(rf, normal_names)
         write_word_table(rf, word_index.word_list)
         write_special_symbols(rf, word_index)
+        write_name_max_len(rf, normal_names, special_groups)
@@ -0,0 +1,109 @@
+// A type for values known to be code points.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{format_codepoint, reserved, CharName, CharNameError, Name, ParseCodePointError};
+
+/// A code point, from U+0000 to U+10FFFF, surrogates included.
+///
+/// Every code point has a name or a code point label, so
+/// [`CodePoint::name`] doesn't return an `Option`. It displays like
+/// `U+0041`, and parses from strings like "U+0041", "0x41" or "41".
+///
+/// ```
+/// use unicode_charname::CodePoint;
+///
+/// let cp: CodePoint = "U+D800".parse().unwrap();
+/// assert_eq!(cp.to_string(), "U+D800");
+/// assert_eq!(cp.name(), "<surrogate-D800>");
+/// assert_eq!(cp.to_char(), None);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CodePoint(u32);
+
+impl CodePoint {
+    /// U+10FFFF, the last code point.
+    pub const MAX: CodePoint = CodePoint(0x10FFFF);
+
+    /// Returns the code point as a `u32`.
+    pub fn value(self) -> u32 {
+        self.0
+    }
+
+    /// Returns the code point as a `char`, or `None` for surrogates.
+    pub fn to_char(self) -> Option<char> {
+        std::char::from_u32(self.0)
+    }
+
+    /// Returns the name of the code point, or its code point label, like
+    /// [`CharName::char_name`].
+    pub fn name(self) -> Name {
+        self.0
+            .char_name()
+            .expect("code points always have a name or a label")
+    }
+
+    /// Returns the code point label of a code point without a name, see
+    /// [`CharName::char_label`].
+    pub fn label(self) -> Option<Name> {
+        self.0.char_label()
+    }
+
+    /// Returns the Name property value of the code point, see
+    /// [`CharName::property_name`].
+    pub fn property_name(self) -> Option<Name> {
+        self.0.property_name()
+    }
+}
+
+impl TryFrom<u32> for CodePoint {
+    type Error = CharNameError;
+
+    fn try_from(value: u32) -> Result<CodePoint, CharNameError> {
+        if reserved::is_code_point(value) {
+            Ok(CodePoint(value))
+        } else {
+            Err(CharNameError::NotACodePoint { value })
+        }
+    }
+}
+
+impl From<char> for CodePoint {
+    fn from(c: char) -> CodePoint {
+        CodePoint(c as u32)
+    }
+}
+
+impl From<CodePoint> for u32 {
+    fn from(cp: CodePoint) -> u32 {
+        cp.0
+    }
+}
+
+/// Displays the code point like `U+0041`, see
+/// [`format_codepoint`](crate::format_codepoint).
+impl fmt::Display for CodePoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&format_codepoint(self.0), f)
+    }
+}
+
+/// Parses hexadecimal digits, optionally after "U+" or "0x".
+impl FromStr for CodePoint {
+    type Err = ParseCodePointError;
+
+    fn from_str(s: &str) -> Result<CodePoint, ParseCodePointError> {
+        let hex = ["U+", "u+", "0x", "0X"]
+            .iter()
+            .find_map(|prefix| s.strip_prefix(prefix))
+            .unwrap_or(s);
+        // `from_str_radix` also takes a sign, which code points don't have.
+        if hex.is_empty() || hex.len() > 8 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ParseCodePointError::Syntax);
+        }
+        let value = u32::from_str_radix(hex, 16).map_err(|_| ParseCodePointError::Syntax)?;
+        CodePoint::try_from(value).map_err(|_| ParseCodePointError::NotACodePoint { value })
+    }
+}
@@ -0,0 +1,165 @@
+// Errors shared by the lookup and parsing functions.
+
+use std::fmt;
+use std::ops::Range;
+
+/// The error returned by
+/// [`CharName::try_char_name`](crate::CharName::try_char_name).
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CharNameError {
+    /// The value is above U+10FFFF.
+    NotACodePoint { value: u32 },
+}
+
+impl fmt::Display for CharNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CharNameError::NotACodePoint { value } => {
+                write!(f, "{:#X} is not a Unicode code point", value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CharNameError {}
+
+/// The error returned by [`Name::custom`](crate::Name::custom) for strings
+/// that don't follow the syntax of character names.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidNameSyntax {
+    pub(crate) offset: usize,
+}
+
+impl InvalidNameSyntax {
+    /// Returns the byte offset of the first offending byte.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for InvalidNameSyntax {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid character name: unexpected byte at offset {}",
+            self.offset
+        )
+    }
+}
+
+impl std::error::Error for InvalidNameSyntax {}
+
+/// The error returned when parsing a
+/// [`CodePoint`](crate::CodePoint) from a string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseCodePointError {
+    /// The string isn't 1 to 8 hexadecimal digits, optionally after "U+"
+    /// or "0x".
+    Syntax,
+    /// The value is above U+10FFFF.
+    NotACodePoint { value: u32 },
+}
+
+impl fmt::Display for ParseCodePointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseCodePointError::Syntax => f.write_str("invalid code point syntax"),
+            ParseCodePointError::NotACodePoint { value } => {
+                write!(f, "{:#X} is not a Unicode code point", value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseCodePointError {}
+
+/// The error returned for a block name that isn't in Blocks.txt, see
+/// [`Filter`](crate::Filter).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownBlockError {
+    pub(crate) name: String,
+}
+
+impl UnknownBlockError {
+    /// Returns the block name as given.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for UnknownBlockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown block name {:?}", self.name)
+    }
+}
+
+impl std::error::Error for UnknownBlockError {}
+
+/// The error returned by [`unescape_named`](crate::unescape_named) for a
+/// `\N{...}` escape it can't resolve.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnescapeError {
+    pub(crate) kind: UnescapeErrorKind,
+    pub(crate) span: Range<usize>,
+    pub(crate) name: String,
+}
+
+/// What is wrong with an escape, see [`UnescapeError`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UnescapeErrorKind {
+    /// `\N` isn't followed by `{`.
+    MissingBrace,
+    /// The `{` has no closing `}`.
+    Unterminated,
+    /// There is another `{` before the closing `}`.
+    NestedBrace,
+    /// No character, alias or named sequence has the name.
+    UnknownName,
+}
+
+impl UnescapeError {
+    /// Returns what is wrong with the escape.
+    pub fn kind(&self) -> UnescapeErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte range of the escape, from the backslash to the
+    /// closing `}`, the nested `{`, or the end of the string.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Returns the name between the braces, up to where the escape ends.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for UnescapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Range { start, end } = self.span;
+        match self.kind {
+            UnescapeErrorKind::MissingBrace => {
+                write!(f, "expected `{{` after `\\N` at bytes {}..{}", start, end)
+            }
+            UnescapeErrorKind::Unterminated => {
+                write!(f, "unterminated `\\N{{` escape at bytes {}..{}", start, end)
+            }
+            UnescapeErrorKind::NestedBrace => write!(
+                f,
+                "unexpected `{{` in the name {:?} at bytes {}..{}",
+                self.name, start, end
+            ),
+            UnescapeErrorKind::UnknownName => write!(
+                f,
+                "unknown character name {:?} at bytes {}..{}",
+                self.name, start, end
+            ),
+        }
+    }
+}
+
+impl std::error::Error for UnescapeError {}
@@ -0,0 +1,111 @@
+//! An FST over the names, behind the `fst` feature.
+
+use std::fmt;
+
+use fst::automaton::{Automaton, Levenshtein, LevenshteinError, Str};
+use fst::{IntoStreamer, Map, Streamer};
+
+/// The names and aliases of every code point with their code points,
+/// built by `scripts/fst-gen` from the generated tables.
+static NAMES_FST: &[u8] = include_bytes!("names.fst");
+
+/// A finite-state transducer mapping every name and alias to its
+/// character, embedded in the library by the `fst` feature.
+///
+/// It holds the same names as [`NameIndex`](crate::NameIndex), the names
+/// derived by rule included, in about 250 KB of static data, and creating
+/// it costs nothing. Besides exact lookup, it finds names by prefix and
+/// within an edit distance, in name order.
+///
+/// ```
+/// use unicode_charname::FstIndex;
+///
+/// let index = FstIndex::new();
+/// assert_eq!(index.get("SNOWMAN"), Some('\u{2603}'));
+/// let fuzzy: Vec<_> = index.fuzzy("SNOWMEN", 1).unwrap().collect();
+/// assert_eq!(fuzzy, [("SNOWMAN".to_string(), '\u{2603}')]);
+/// ```
+#[derive(Clone)]
+pub struct FstIndex {
+    map: Map<&'static [u8]>,
+}
+
+impl FstIndex {
+    /// Returns the index of the embedded FST.
+    pub fn new() -> FstIndex {
+        let map = Map::new(NAMES_FST).expect("the embedded FST is valid");
+        FstIndex { map }
+    }
+
+    /// Returns the character named `name`, like [`lookup`](crate::lookup).
+    pub fn get(&self, name: &str) -> Option<char> {
+        let v = self.map.get(name)?;
+        std::char::from_u32(v as u32)
+    }
+
+    /// Returns the names and aliases starting with `prefix`, with their
+    /// characters, in name order.
+    pub fn prefix_iter<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = (String, char)> + 'a {
+        let stream = self
+            .map
+            .search(Str::new(prefix).starts_with())
+            .into_stream();
+        Names(stream)
+    }
+
+    /// Returns the names and aliases within Levenshtein distance `distance`
+    /// of `query`, with their characters, in name order.
+    ///
+    /// Names are uppercase, and so should `query` be. Fails if the
+    /// automaton for `query` would be too large, as with long queries and
+    /// large distances.
+    pub fn fuzzy<'a>(
+        &'a self,
+        query: &str,
+        distance: u32,
+    ) -> Result<impl Iterator<Item = (String, char)> + 'a, LevenshteinError> {
+        let automaton = Levenshtein::new(query, distance)?;
+        Ok(Names(self.map.search(automaton).into_stream()))
+    }
+
+    /// Returns the number of names and aliases in the index.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns whether the index is empty, which it never is.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+impl Default for FstIndex {
+    fn default() -> FstIndex {
+        FstIndex::new()
+    }
+}
+
+impl fmt::Debug for FstIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FstIndex")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// Turns a stream of the FST into an iterator of owned names.
+struct Names<S>(S);
+
+impl<S> Iterator for Names<S>
+where
+    S: for<'s> Streamer<'s, Item = (&'s [u8], u64)>,
+{
+    type Item = (String, char);
+
+    fn next(&mut self) -> Option<(String, char)> {
+        let (name, v) = self.0.next()?;
+        let name = std::str::from_utf8(name).expect("names are ASCII");
+        let c = std::char::from_u32(v as u32).expect("the FST has characters");
+        Some((name.to_string(), c))
+    }
+}
@@ -0,0 +1,185 @@
+//! Suggestions of names close to a misspelled one.
+
+use crate::{find_word_index, tables, CharName, Name};
+
+/// Returns up to `limit` names closest to `query`, with their characters
+/// and scores, best first.
+///
+/// The score counts the edits turning `query` into the name word by word:
+/// adding or dropping a word is one edit, and so is each character edit
+/// within a word, where swapping two adjacent characters counts as one,
+/// up to two for replacing the word. Zero means an exact match, and case
+/// is ignored. Names with the same score are ranked by their character
+/// edit distance to `query`, then by code point. Only the names listed in
+/// UnicodeData.txt are suggested, not the names derived by rule like
+/// "HANGUL SYLLABLE GAG".
+///
+/// Names sharing more words with `query` are scored first, so that the
+/// character edit distance of most other names needn't be computed in
+/// full.
+///
+/// ```
+/// let suggestions = unicode_charname::closest_names("GREK SMALL LETTER ALPHA", 3);
+/// let (c, name, score) = &suggestions[0];
+/// assert_eq!(*c, '\u{3B1}');
+/// assert_eq!(*name, "GREEK SMALL LETTER ALPHA");
+/// assert_eq!(*score, 1);
+/// ```
+pub fn closest_names(query: &str, limit: usize) -> Vec<(char, Name, u32)> {
+    if limit == 0 {
+        return Vec::new();
+    }
+    let query = query.to_ascii_uppercase();
+    let query_words: Vec<&str> = query
+        .split([' ', '-'])
+        .filter(|word| !word.is_empty())
+        .collect();
+    let query_word_indices: Vec<u16> = query_words
+        .iter()
+        .filter_map(|&word| find_word_index(word))
+        .collect();
+
+    let mut candidates = Vec::with_capacity(tables::NAMES_BY_NAME.len());
+    for &(first, last, index_slice, offset_slice) in tables::ENUMERATION_CHAR_NAMES {
+        for v in first..=last {
+            let offset = (v - first) as usize;
+            let range = (offset_slice[offset] as usize)..(offset_slice[offset + 1] as usize);
+            let encoded_slice = &index_slice[range];
+            let shared = query_word_indices
+                .iter()
+                .filter(|word_idx| encoded_slice.contains(word_idx))
+                .count();
+            candidates.push((shared, v));
+        }
+    }
+    candidates.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+    // the best names so far, by score, character edit distance and code
+    // point.
+    let mut best: Vec<(u32, u32, u32)> = Vec::with_capacity(limit + 1);
+    let mut scratch = Scratch::default();
+    let mut buf = String::new();
+    for &(_, v) in &candidates {
+        let name = v.property_name().expect("the name tables have names");
+        let worst = if best.len() == limit {
+            best[limit - 1]
+        } else {
+            (u32::MAX, u32::MAX, u32::MAX)
+        };
+        let score = match word_edit_distance(&query_words, &name, worst.0, &mut scratch) {
+            Some(score) => score,
+            None => continue,
+        };
+        let cutoff = if score == worst.0 {
+            // ties need a smaller distance, or the same one and a lower
+            // code point.
+            worst.1.saturating_add(u32::from(v < worst.2))
+        } else {
+            u32::MAX
+        };
+        // each edit changes the length by at most one.
+        if name.len().abs_diff(query.len()) >= cutoff as usize {
+            continue;
+        }
+        buf.clear();
+        name.write_to(&mut buf)
+            .expect("writing to a `String` never fails");
+        let distance =
+            match edit_distance(query.as_bytes(), buf.as_bytes(), cutoff, &mut scratch.chars) {
+                Some(distance) => distance,
+                None => continue,
+            };
+        let entry = (score, distance, v);
+        let idx = best.partition_point(|&other| other < entry);
+        best.insert(idx, entry);
+        best.truncate(limit);
+    }
+    best.into_iter()
+        .map(|(score, _, v)| {
+            let c = std::char::from_u32(v).expect("the name tables have characters");
+            let name = v.property_name().expect("the name tables have names");
+            (c, name, score)
+        })
+        .collect()
+}
+
+/// The rows of the tables of [`word_edit_distance`] and [`edit_distance`],
+/// reused between names.
+#[derive(Default)]
+struct Scratch {
+    words: [Vec<u32>; 2],
+    chars: [Vec<u32>; 3],
+}
+
+/// Returns the number of edits turning the words `query_words` into the
+/// words of `name`, see [`closest_names`], or `None` if it is above
+/// `cutoff`.
+fn word_edit_distance(
+    query_words: &[&str],
+    name: &Name,
+    cutoff: u32,
+    scratch: &mut Scratch,
+) -> Option<u32> {
+    let [prev, cur] = &mut scratch.words;
+    prev.clear();
+    prev.extend(0..=query_words.len() as u32);
+    cur.clear();
+    cur.resize(query_words.len() + 1, 0);
+    for (i, word) in name.words().enumerate() {
+        cur[0] = i as u32 + 1;
+        let mut row_min = cur[0];
+        for (j, query_word) in query_words.iter().enumerate() {
+            let replace = edit_distance(
+                query_word.as_bytes(),
+                word.as_bytes(),
+                2,
+                &mut scratch.chars,
+            )
+            .unwrap_or(2);
+            cur[j + 1] = (prev[j + 1] + 1).min(cur[j] + 1).min(prev[j] + replace);
+            row_min = row_min.min(cur[j + 1]);
+        }
+        // later rows can't get below this one.
+        if row_min > cutoff {
+            return None;
+        }
+        std::mem::swap(prev, cur);
+    }
+    Some(prev[query_words.len()]).filter(|&d| d <= cutoff)
+}
+
+/// Returns the optimal string alignment distance between `a` and `b`, or
+/// `None` if it isn't below `cutoff`.
+fn edit_distance(a: &[u8], b: &[u8], cutoff: u32, rows: &mut [Vec<u32>; 3]) -> Option<u32> {
+    // three rows of the usual table, for transpositions.
+    let [before_prev, prev, cur] = rows;
+    before_prev.clear();
+    before_prev.resize(b.len() + 1, 0);
+    prev.clear();
+    prev.extend(0..=b.len() as u32);
+    cur.clear();
+    cur.resize(b.len() + 1, 0);
+    let mut prev_min = 0;
+    for i in 1..=a.len() {
+        cur[0] = i as u32;
+        let mut row_min = cur[0];
+        for j in 1..=b.len() {
+            let cost = u32::from(a[i - 1] != b[j - 1]);
+            let mut d = (prev[j] + 1).min(cur[j - 1] + 1).min(prev[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d = d.min(before_prev[j - 2] + 1);
+            }
+            cur[j] = d;
+            row_min = row_min.min(d);
+        }
+        // later rows can't get below this row, or below the previous one
+        // through a transposition.
+        if row_min >= cutoff && prev_min >= cutoff {
+            return None;
+        }
+        prev_min = row_min;
+        std::mem::swap(before_prev, prev);
+        std::mem::swap(prev, cur);
+    }
+    Some(prev[b.len()]).filter(|&d| d < cutoff)
+}
@@ -0,0 +1,73 @@
+//! Matching names against `*` and `?` wildcard patterns.
+
+use crate::{CharName, Name};
+
+/// Returns the characters whose names match the wildcard `pattern`, with
+/// their names, in code point order.
+///
+/// A `*` matches any run of characters, spaces included, and a `?` matches
+/// exactly one character. Everything else matches itself, ignoring ASCII
+/// case, so a pattern without wildcards finds the character with that
+/// name. The names derived by rule, like "HANGUL SYLLABLE GAG", are
+/// searched too, but not code point labels.
+///
+/// ```
+/// let names: String = unicode_charname::names_matching_glob("latin * letter a with *ring*")
+///     .map(|(c, _)| c)
+///     .collect();
+/// assert_eq!(names, "\u{C5}\u{E5}\u{1FA}\u{1FB}\u{1E00}\u{1E01}\u{1E9A}");
+/// ```
+pub fn names_matching_glob(pattern: &str) -> impl Iterator<Item = (char, Name)> + '_ {
+    (0..=0x10_FFFFu32).filter_map(move |v| {
+        let name = v.property_name()?;
+        if !matches(pattern.as_bytes(), name.iter().flat_map(str::bytes)) {
+            return None;
+        }
+        let c = std::char::from_u32(v).expect("named code points are characters");
+        Some((c, name))
+    })
+}
+
+/// Returns whether the bytes of `text` match `pattern`.
+///
+/// Names are ASCII, so each byte of a name is a character. On a mismatch
+/// only the last `*` is retried one byte further, as the runs matched by
+/// earlier ones never need to grow.
+fn matches<I: Iterator<Item = u8> + Clone>(pattern: &[u8], mut text: I) -> bool {
+    let mut p = 0;
+    // the pattern after the last `*`, and where in `text` to try it next.
+    let mut retry: Option<(usize, I)> = None;
+    loop {
+        let mut rest = text.clone();
+        let next = rest.next();
+        match (pattern.get(p), next) {
+            (Some(b'*'), _) => {
+                p += 1;
+                retry = Some((p, text.clone()));
+                continue;
+            }
+            (Some(b'?'), Some(_)) => {
+                p += 1;
+                text = rest;
+                continue;
+            }
+            (Some(c), Some(b)) if c.eq_ignore_ascii_case(&b) => {
+                p += 1;
+                text = rest;
+                continue;
+            }
+            (None, None) => return true,
+            _ => {}
+        }
+        match &mut retry {
+            Some((star_end, star_text)) => {
+                if star_text.next().is_none() {
+                    return false;
+                }
+                p = *star_end;
+                text = star_text.clone();
+            }
+            None => return false,
+        }
+    }
+}
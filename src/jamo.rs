@@ -1,6 +1,9 @@
-#![allow(dead_code)]
+//! Short names of the conjoining jamo, as used in the names of Hangul
+//! syllables.
 // This is adapted from Unicode 13.0, 3.12.
 
+use std::fmt;
+
 const S_BASE: u32 = 0xAC00;
 const L_BASE: u32 = 0x1100;
 const V_BASE: u32 = 0x1161;
@@ -11,31 +14,139 @@ const T_COUNT: u32 = 28;
 const N_COUNT: u32 = V_COUNT * T_COUNT; // 588
 const S_COUNT: u32 = L_COUNT * N_COUNT; // 11172
 
-const JAMO_L_TABLE: &[&'static str] = &[
+const JAMO_L_TABLE: &[&str] = &[
     "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T", "P",
     "H",
 ];
 
-const JAMO_V_TABLE: &[&'static str] = &[
+const JAMO_V_TABLE: &[&str] = &[
     "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO", "WE",
     "WI", "YU", "EU", "YI", "I",
 ];
 
-const JAMO_T_TABLE: &[&'static str] = &[
+const JAMO_T_TABLE: &[&str] = &[
     "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH", "M",
     "B", "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H",
 ];
 
-pub(crate) fn hangul_name(s: u32) -> String {
-    let s_index = s - S_BASE;
-    assert!(s_index < S_COUNT);
+pub(crate) fn write_hangul_name<W: fmt::Write>(w: &mut W, s: u32) -> fmt::Result {
+    let (l, v, t) = hangul_name_parts(s).expect("a Hangul syllable");
+    write!(w, "HANGUL SYLLABLE {}{}{}", l, v, t.unwrap_or(""))
+}
+
+/// Returns the Hangul syllable named "HANGUL SYLLABLE " followed by `s`,
+/// like U+AC01 for "GAG".
+pub(crate) fn hangul_syllable_from_name(s: &str) -> Option<u32> {
+    // some short names are prefixes of others, like "G" of "GG", so try
+    // every split rather than the longest match.
+    for (l_index, l) in JAMO_L_TABLE.iter().enumerate() {
+        let after_l = match s.strip_prefix(l) {
+            Some(after_l) => after_l,
+            None => continue,
+        };
+        for (v_index, v) in JAMO_V_TABLE.iter().enumerate() {
+            let t = match after_l.strip_prefix(v) {
+                Some(t) => t,
+                None => continue,
+            };
+            if let Some(t_index) = JAMO_T_TABLE.iter().position(|&short_name| short_name == t) {
+                let index = (l_index as u32 * V_COUNT + v_index as u32) * T_COUNT + t_index as u32;
+                return Some(S_BASE + index);
+            }
+        }
+    }
+    None
+}
+
+/// Splits a Hangul syllable into the indices of its jamo.
+fn syllable_indices(s: u32) -> Option<(u32, u32, u32)> {
+    let s_index = s.checked_sub(S_BASE).filter(|&s_index| s_index < S_COUNT)?;
     let l_index = s_index / N_COUNT;
     let v_index = (s_index % N_COUNT) / T_COUNT;
     let t_index = s_index % T_COUNT;
-    format!(
-        "HANGUL SYLLABLE {}{}{}",
+    Some((l_index, v_index, t_index))
+}
+
+/// Returns the short names of the jamo of a Hangul syllable, like
+/// ("G", "A", Some("M")) for U+AC10 HANGUL SYLLABLE GAM.
+///
+/// Returns `None` when `syllable` isn't a precomposed Hangul syllable.
+pub fn hangul_name_parts(
+    syllable: u32,
+) -> Option<(&'static str, &'static str, Option<&'static str>)> {
+    let (l_index, v_index, t_index) = syllable_indices(syllable)?;
+    let t = if t_index == 0 {
+        None
+    } else {
+        Some(JAMO_T_TABLE[t_index as usize])
+    };
+    Some((
         JAMO_L_TABLE[l_index as usize],
         JAMO_V_TABLE[v_index as usize],
-        JAMO_T_TABLE[t_index as usize]
-    )
+        t,
+    ))
+}
+
+/// Returns the conjoining jamo a Hangul syllable decomposes into, like
+/// U+1100, U+1161 and U+11B7 for U+AC10 HANGUL SYLLABLE GAM.
+///
+/// Returns `None` when `syllable` isn't a precomposed Hangul syllable.
+pub fn hangul_jamos(syllable: u32) -> Option<(char, char, Option<char>)> {
+    let (l_index, v_index, t_index) = syllable_indices(syllable)?;
+    let jamo = |base: u32, index: u32| std::char::from_u32(base + index).expect("a jamo");
+    let t = if t_index == 0 {
+        None
+    } else {
+        Some(jamo(T_BASE, t_index))
+    };
+    Some((jamo(L_BASE, l_index), jamo(V_BASE, v_index), t))
+}
+
+/// Returns the short name of a leading consonant jamo, like "G" for
+/// U+1100 HANGUL CHOSEONG KIYEOK.
+///
+/// The short name of U+110B HANGUL CHOSEONG IEUNG is empty.
+pub fn leading_consonant_short_name(c: char) -> Option<&'static str> {
+    short_name(JAMO_L_TABLE, L_BASE, c as u32)
+}
+
+/// Returns the short name of a vowel jamo, like "A" for
+/// U+1161 HANGUL JUNGSEONG A.
+pub fn vowel_short_name(c: char) -> Option<&'static str> {
+    short_name(JAMO_V_TABLE, V_BASE, c as u32)
+}
+
+/// Returns the short name of a trailing consonant jamo, like "G" for
+/// U+11A8 HANGUL JONGSEONG KIYEOK.
+pub fn trailing_consonant_short_name(c: char) -> Option<&'static str> {
+    // index 0 stands for no trailing consonant.
+    short_name(&JAMO_T_TABLE[1..], T_BASE + 1, c as u32)
+}
+
+/// Returns the leading consonant jamo with the short name `s`, see
+/// [`leading_consonant_short_name`].
+pub fn leading_consonant_from_short_name(s: &str) -> Option<char> {
+    from_short_name(JAMO_L_TABLE, L_BASE, s)
+}
+
+/// Returns the vowel jamo with the short name `s`, see
+/// [`vowel_short_name`].
+pub fn vowel_from_short_name(s: &str) -> Option<char> {
+    from_short_name(JAMO_V_TABLE, V_BASE, s)
+}
+
+/// Returns the trailing consonant jamo with the short name `s`, see
+/// [`trailing_consonant_short_name`].
+pub fn trailing_consonant_from_short_name(s: &str) -> Option<char> {
+    from_short_name(&JAMO_T_TABLE[1..], T_BASE + 1, s)
+}
+
+fn short_name(table: &'static [&'static str], base: u32, c: u32) -> Option<&'static str> {
+    let index = c.checked_sub(base)?;
+    table.get(index as usize).copied()
+}
+
+fn from_short_name(table: &[&str], base: u32, s: &str) -> Option<char> {
+    let index = table.iter().position(|&short_name| short_name == s)?;
+    std::char::from_u32(base + index as u32)
 }
@@ -1,185 +1,2791 @@
-use std::fmt;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt::{self, Write as _};
+use std::hash::{Hash, Hasher};
+use std::num::NonZeroU32;
+use std::ops::{Range, RangeInclusive};
+
+use small_str::{InlineStr, SmallString};
 
 #[rustfmt::skip]
 mod tables;
 
-mod jamo;
+mod code_point;
+mod error;
+#[cfg(feature = "fst")]
+mod fst_index;
+mod fuzzy;
+mod glob;
+pub mod jamo;
+mod name_index;
+#[cfg(feature = "regex")]
+mod regex_search;
 mod reserved;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod small_str;
+mod syntax;
+mod unescape;
+
+pub use code_point::CodePoint;
+pub use error::{
+    CharNameError, InvalidNameSyntax, ParseCodePointError, UnescapeError, UnescapeErrorKind,
+    UnknownBlockError,
+};
+#[cfg(feature = "fst")]
+pub use fst_index::FstIndex;
+pub use fuzzy::closest_names;
+pub use glob::names_matching_glob;
+pub use name_index::NameIndex;
+#[cfg(feature = "regex")]
+pub use regex_search::names_matching;
+#[cfg(feature = "emoji-names")]
+pub use tables::EMOJI_VERSION;
+pub use tables::{NAME_MAX_LEN, UNICODE_VERSION};
+pub use unescape::{unescape_named, unescape_named_loose};
+
+/// Looks up the names of code points, given as `char`, [`CodePoint`], `u32`,
+/// `u16`, `u64` or `usize`.
+///
+/// Every `u16` is a code point, the surrogates U+D800 to U+DFFF included,
+/// so those get labels like `<surrogate-D800>` rather than `None`. `u64` and
+/// `usize` values above U+10FFFF have no name, and those above `u32::MAX`
+/// are looked up as `u32::MAX`.
+pub trait CharName {
+    fn char_name(self) -> Option<Name>;
+    fn property_name(self) -> Option<Name>;
+
+    /// Like [`CharName::char_name`], with an error telling why there is
+    /// no name.
+    fn try_char_name(self) -> Result<Name, CharNameError>;
+
+    /// Displays the name like [`CharName::char_name`], see
+    /// [`char_name_display`].
+    fn char_name_display(self) -> CharNameDisplay;
+
+    /// Displays the code point like `U+0041`, see [`format_codepoint`].
+    fn format_codepoint(self) -> CodePointNotation;
+
+    /// Returns the formal aliases of the character from NameAliases.txt,
+    /// in file order, or an empty slice when it has none.
+    fn name_aliases(self) -> &'static [(AliasKind, &'static str)];
+
+    /// Returns the correction alias of the character when its name has
+    /// been corrected, like "PRESENTATION FORM FOR VERTICAL RIGHT WHITE
+    /// LENTICULAR BRACKET" for U+FE18, or [`CharName::char_name`] otherwise.
+    fn corrected_name(self) -> Option<Name>;
+
+    /// Returns the first control alias of a control character, like
+    /// "LINE FEED" for U+000A.
+    ///
+    /// Returns `None` for other characters, and for U+0080, U+0081 and
+    /// U+0099 which have no control alias.
+    fn control_alias(self) -> Option<&'static str>
+    where
+        Self: Sized,
+    {
+        self.name_aliases()
+            .iter()
+            .find(|&&(kind, _)| kind == AliasKind::Control)
+            .map(|&(_, alias)| alias)
+    }
+
+    /// Returns the most readable name of the character: its control
+    /// alias, then its name, then its code point label.
+    fn readable_name(self) -> Option<Name>;
+
+    /// Returns the shortest abbreviation alias of the character, like
+    /// "ZWJ" for U+200D, the first one in file order when several are
+    /// equally short.
+    fn abbreviation(self) -> Option<&'static str>
+    where
+        Self: Sized,
+    {
+        self.name_aliases()
+            .iter()
+            .filter(|&&(kind, _)| kind == AliasKind::Abbreviation)
+            .map(|&(_, alias)| alias)
+            .min_by_key(|alias| alias.len())
+    }
+
+    /// Returns the shortest form of the name for display: the
+    /// [`CharName::abbreviation`] of the character, else its name like
+    /// [`CharName::char_name`].
+    fn short_display(self) -> Option<Name>;
+
+    /// Returns the Unicode 1.0 name of the character, like "BELL" for
+    /// U+0007, when it differs from its current name or it has none.
+    fn unicode_1_0_name(self) -> Option<&'static str>;
+
+    /// Returns the block the code point belongs to, from Blocks.txt.
+    ///
+    /// Returns `None` for code points outside any block.
+    fn block(self) -> Option<Block>;
+
+    /// Returns the version of Unicode the code point was assigned in, from
+    /// DerivedAge.txt.
+    ///
+    /// Returns `None` for unassigned code points.
+    fn age(self) -> Option<UnicodeVersion>;
+
+    /// Returns the name of the character as of an older version of Unicode,
+    /// with a code point label like `<reserved-32FF>` for characters
+    /// assigned after `version`.
+    ///
+    /// Only the assignment age is considered, later renames aren't undone.
+    fn char_name_in(self, version: UnicodeVersion) -> Option<Name>;
+
+    /// Returns the Name property value as of an older version of Unicode,
+    /// see [`CharName::char_name_in`].
+    fn property_name_in(self, version: UnicodeVersion) -> Option<Name>;
+
+    /// Returns the plane of the code point, from 0 to 16.
+    ///
+    /// Returns `None` for values above U+10FFFF.
+    fn plane(self) -> Option<u8>;
+
+    /// Returns the name of the plane of the code point, like
+    /// "Supplementary Multilingual Plane", see [`CharName::plane`].
+    fn plane_name(self) -> Option<&'static str>
+    where
+        Self: Sized,
+    {
+        self.plane()
+            .map(|plane| reserved::PLANE_NAMES[plane as usize])
+    }
+
+    /// Returns the name of the block the code point belongs to, like
+    /// "Arabic Presentation Forms-A", see [`CharName::block`].
+    fn block_name(self) -> Option<&'static str>
+    where
+        Self: Sized,
+    {
+        self.block().map(|block| block.name())
+    }
+
+    /// Returns the code point label of code points without a name, like
+    /// "control-0009", without the angle brackets of [`CharName::char_name`].
+    ///
+    /// Returns `None` for characters with a name.
+    fn char_label(self) -> Option<Name>;
+
+    /// Like [`CharName::char_label`], with the name of the block of
+    /// reserved code points, like "reserved-061D (Arabic)".
+    ///
+    /// Reserved code points outside any block, and other labels, are
+    /// returned as is, telling unassigned code points in allocated blocks
+    /// from those in unallocated areas.
+    fn char_label_with_block(self) -> Option<Name>;
+
+    /// Returns the name Java's `Character.getName` gives the code point.
+    ///
+    /// That's the name listed in UnicodeData.txt, or the Unicode 1.0 name
+    /// of controls, like "LINE FEED (LF)". Other assigned code points are
+    /// named after their block, like "CJK UNIFIED IDEOGRAPHS 4E00", with
+    /// the hexadecimal code point unpadded, like "LATIN 1 SUPPLEMENT 80".
+    /// Unlike [`CharName::char_name`], Hangul syllables and ideographs get
+    /// block names too, and unassigned code points get `None` rather than
+    /// a label.
+    fn java_style_name(self) -> Option<String>;
+
+    /// Returns the basic type of the code point, as in Table 2-3 of the
+    /// Unicode Standard.
+    ///
+    /// Returns `None` for values above U+10FFFF.
+    fn code_point_type(self) -> Option<CodePointType>;
+
+    /// Returns the rule that derives the name returned by
+    /// [`CharName::char_name`], without building it.
+    ///
+    /// Returns `None` for values above U+10FFFF.
+    fn name_derivation(self) -> Option<NameDerivation>;
+
+    /// Displays the character along with its code point and name, see
+    /// [`annotate`].
+    ///
+    /// Returns `None` for surrogates and values above U+10FFFF.
+    fn annotate(self) -> Option<DisplayWithName>;
+
+    /// Returns whether the character has a Name property value, like
+    /// `property_name().is_some()` but without building the name.
+    fn has_explicit_name(self) -> bool
+    where
+        Self: Sized,
+    {
+        match self.name_derivation() {
+            Some(NameDerivation::Label(_)) | None => false,
+            Some(_) => true,
+        }
+    }
+
+    /// Returns whether the character has a name or a code point label, like
+    /// `char_name().is_some()` but without building it.
+    fn has_name_or_label(self) -> bool
+    where
+        Self: Sized,
+    {
+        self.name_derivation().is_some()
+    }
+
+    /// Writes the name returned by [`CharName::char_name`] into `buf`,
+    /// replacing its contents, and returns whether there is a name.
+    ///
+    /// `buf` is left empty when there is no name. Names are built without
+    /// allocating, so reusing a `buf` with a capacity of [`NAME_MAX_LEN`]
+    /// never allocates.
+    fn name_into(self, buf: &mut String) -> bool
+    where
+        Self: Sized,
+    {
+        buf.clear();
+        match self.char_name() {
+            Some(name) => {
+                name.write_to(buf).expect("writing to a String can't fail");
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Like [`CharName::name_into`], for the name returned by
+    /// [`CharName::property_name`].
+    fn property_name_into(self, buf: &mut String) -> bool
+    where
+        Self: Sized,
+    {
+        buf.clear();
+        match self.property_name() {
+            Some(name) => {
+                name.write_to(buf).expect("writing to a String can't fail");
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the `\N{...}` escape of the character, see [`Name::escape`].
+    fn name_escape(self) -> Option<String>
+    where
+        Self: Sized,
+    {
+        self.char_name().map(|name| name.escape().to_string())
+    }
+}
+
+/// Converts values of other types to `u32` for the [`CharName`] impls.
+trait IntoCodePoint {
+    fn into_code_point(self) -> u32;
+}
+
+impl IntoCodePoint for char {
+    fn into_code_point(self) -> u32 {
+        self as u32
+    }
+}
+
+impl IntoCodePoint for u16 {
+    fn into_code_point(self) -> u32 {
+        u32::from(self)
+    }
+}
+
+impl IntoCodePoint for u64 {
+    fn into_code_point(self) -> u32 {
+        // anything above `u32::MAX` is out of range as well.
+        u32::try_from(self).unwrap_or(u32::MAX)
+    }
+}
+
+impl IntoCodePoint for usize {
+    fn into_code_point(self) -> u32 {
+        u32::try_from(self).unwrap_or(u32::MAX)
+    }
+}
+
+impl IntoCodePoint for CodePoint {
+    fn into_code_point(self) -> u32 {
+        self.value()
+    }
+}
+
+/// Implements [`CharName`] by forwarding to the `u32` implementation.
+macro_rules! forward_char_name {
+    ($($ty:ty),*) => {
+        $(
+            impl CharName for $ty {
+                fn char_name(self) -> Option<Name> {
+                    CharName::char_name(self.into_code_point())
+                }
+                fn property_name(self) -> Option<Name> {
+                    CharName::property_name(self.into_code_point())
+                }
+                fn try_char_name(self) -> Result<Name, CharNameError> {
+                    CharName::try_char_name(self.into_code_point())
+                }
+                fn char_name_display(self) -> CharNameDisplay {
+                    char_name_display(self.into_code_point())
+                }
+                fn format_codepoint(self) -> CodePointNotation {
+                    format_codepoint(self.into_code_point())
+                }
+                fn name_aliases(self) -> &'static [(AliasKind, &'static str)] {
+                    CharName::name_aliases(self.into_code_point())
+                }
+                fn corrected_name(self) -> Option<Name> {
+                    CharName::corrected_name(self.into_code_point())
+                }
+                fn readable_name(self) -> Option<Name> {
+                    CharName::readable_name(self.into_code_point())
+                }
+                fn short_display(self) -> Option<Name> {
+                    CharName::short_display(self.into_code_point())
+                }
+                fn unicode_1_0_name(self) -> Option<&'static str> {
+                    CharName::unicode_1_0_name(self.into_code_point())
+                }
+                fn block(self) -> Option<Block> {
+                    CharName::block(self.into_code_point())
+                }
+                fn plane(self) -> Option<u8> {
+                    CharName::plane(self.into_code_point())
+                }
+                fn age(self) -> Option<UnicodeVersion> {
+                    CharName::age(self.into_code_point())
+                }
+                fn char_name_in(self, version: UnicodeVersion) -> Option<Name> {
+                    CharName::char_name_in(self.into_code_point(), version)
+                }
+                fn property_name_in(self, version: UnicodeVersion) -> Option<Name> {
+                    CharName::property_name_in(self.into_code_point(), version)
+                }
+                fn char_label(self) -> Option<Name> {
+                    CharName::char_label(self.into_code_point())
+                }
+                fn char_label_with_block(self) -> Option<Name> {
+                    CharName::char_label_with_block(self.into_code_point())
+                }
+                fn java_style_name(self) -> Option<String> {
+                    CharName::java_style_name(self.into_code_point())
+                }
+                fn code_point_type(self) -> Option<CodePointType> {
+                    CharName::code_point_type(self.into_code_point())
+                }
+                fn name_derivation(self) -> Option<NameDerivation> {
+                    CharName::name_derivation(self.into_code_point())
+                }
+                fn annotate(self) -> Option<DisplayWithName> {
+                    CharName::annotate(self.into_code_point())
+                }
+            }
+        )*
+    };
+}
+
+forward_char_name!(char, u16, u64, usize, CodePoint);
+
+impl CharName for u32 {
+    fn char_name(self) -> Option<Name> {
+        name(self)
+    }
+
+    fn property_name(self) -> Option<Name> {
+        match classify(self)? {
+            Class::Named(slice) => Some(Name(NameInner::Enumeration {
+                encoded_slice: slice,
+                code_point: PackedCodePoint::some(self),
+                codepoint_repr: hex_repr(self),
+            })),
+            Class::SpecialGroup(special_group) => name_for_special_group_char(self, special_group),
+            Class::Unassigned(_) => None,
+        }
+    }
+
+    fn try_char_name(self) -> Result<Name, CharNameError> {
+        name(self).ok_or(CharNameError::NotACodePoint { value: self })
+    }
+
+    fn char_name_display(self) -> CharNameDisplay {
+        char_name_display(self)
+    }
+
+    fn format_codepoint(self) -> CodePointNotation {
+        format_codepoint(self)
+    }
+
+    fn name_aliases(self) -> &'static [(AliasKind, &'static str)] {
+        tables::find_name_aliases(self)
+    }
+
+    fn corrected_name(self) -> Option<Name> {
+        match correction_alias(self) {
+            Some(alias) => Some(alias_name(self, alias)),
+            None => name(self),
+        }
+    }
+
+    fn readable_name(self) -> Option<Name> {
+        match self.control_alias() {
+            Some(alias) => Some(alias_name(self, alias)),
+            None => name(self),
+        }
+    }
+
+    fn short_display(self) -> Option<Name> {
+        match self.abbreviation() {
+            Some(alias) => Some(alias_name(self, alias)),
+            None => name(self),
+        }
+    }
+
+    fn unicode_1_0_name(self) -> Option<&'static str> {
+        tables::find_unicode_1_name(self)
+    }
+
+    fn block(self) -> Option<Block> {
+        let (first, last, name) = tables::find_block(self)?;
+        Some(Block { first, last, name })
+    }
+
+    fn plane(self) -> Option<u8> {
+        reserved::plane(self)
+    }
+
+    fn age(self) -> Option<UnicodeVersion> {
+        let (major, minor) = tables::find_age(self)?;
+        Some(UnicodeVersion {
+            major,
+            minor,
+            micro: 0,
+        })
+    }
+
+    fn char_name_in(self, version: UnicodeVersion) -> Option<Name> {
+        match self.age() {
+            Some(age) if age > version => unassigned_code_point_label(self, LabelStyle::BRACKETED),
+            _ => name(self),
+        }
+    }
+
+    fn property_name_in(self, version: UnicodeVersion) -> Option<Name> {
+        match self.age() {
+            Some(age) if age > version => None,
+            _ => self.property_name(),
+        }
+    }
+
+    fn char_label(self) -> Option<Name> {
+        label(self)
+    }
+
+    fn char_label_with_block(self) -> Option<Name> {
+        match self.block() {
+            Some(block) if self.code_point_type() == Some(CodePointType::Reserved) => {
+                Some(block_label_name(self, block))
+            }
+            _ => label(self),
+        }
+    }
+
+    fn java_style_name(self) -> Option<String> {
+        match self.code_point_type()? {
+            CodePointType::Noncharacter | CodePointType::Reserved => return None,
+            CodePointType::Control => {
+                if let Some(name) = self.unicode_1_0_name() {
+                    return Some(name.to_owned());
+                }
+            }
+            _ => {
+                if self.name_derivation() == Some(NameDerivation::Explicit) {
+                    return self.property_name().map(Name::into_string);
+                }
+            }
+        }
+        let block = self.block()?;
+        Some(format!("{} {:X}", java_block_name(block.name()), self))
+    }
+
+    fn code_point_type(self) -> Option<CodePointType> {
+        let code_point_type = match classify(self)? {
+            Class::Named(_) if tables::is_format_char(self) => CodePointType::Format,
+            Class::Named(_) => CodePointType::Graphic,
+            Class::SpecialGroup(special_group) => match special_group_kind(special_group) {
+                GeneratedKind::Label(LabelKind::Control) => CodePointType::Control,
+                GeneratedKind::Label(LabelKind::Surrogate) => CodePointType::Surrogate,
+                GeneratedKind::Label(LabelKind::PrivateUse) => CodePointType::PrivateUse,
+                _ => CodePointType::Graphic,
+            },
+            Class::Unassigned(LabelKind::Noncharacter) => CodePointType::Noncharacter,
+            Class::Unassigned(_) => CodePointType::Reserved,
+        };
+        Some(code_point_type)
+    }
+
+    fn name_derivation(self) -> Option<NameDerivation> {
+        let name_derivation = match classify(self)? {
+            Class::Named(_) => NameDerivation::Explicit,
+            Class::SpecialGroup(special_group) => {
+                special_group_kind(special_group).to_name_derivation()
+            }
+            Class::Unassigned(kind) => NameDerivation::Label(kind),
+        };
+        Some(name_derivation)
+    }
+
+    fn annotate(self) -> Option<DisplayWithName> {
+        char::from_u32(self).map(annotate)
+    }
+}
+
+/// Looks up the names of the characters of a string.
+///
+/// ```
+/// use unicode_charname::{CharName, StrCharNames};
+///
+/// for (offset, c, name) in "e\u{301}\u{1F402}".char_indices_names() {
+///     println!("{:>2} {:?} {} {}", offset, c, c.format_codepoint(), name);
+/// }
+/// let names = "e\u{301}\u{1F402}"
+///     .char_names()
+///     .map(|(_, name)| name.to_string())
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     names,
+///     ["LATIN SMALL LETTER E", "COMBINING ACUTE ACCENT", "OX"]
+/// );
+/// ```
+pub trait StrCharNames {
+    /// Returns an iterator over the characters of the string along with
+    /// their names, or labels like `<control-0009>`.
+    fn char_names(&self) -> CharNames<'_>;
+
+    /// Like [`StrCharNames::char_names`], with the byte offset of each
+    /// character.
+    fn char_indices_names(&self) -> CharIndicesNames<'_>;
+}
+
+impl StrCharNames for str {
+    fn char_names(&self) -> CharNames<'_> {
+        CharNames {
+            chars: self.chars(),
+        }
+    }
+
+    fn char_indices_names(&self) -> CharIndicesNames<'_> {
+        CharIndicesNames {
+            char_indices: self.char_indices(),
+        }
+    }
+}
+
+/// Looks up the names of `char`s, which always have a name or a code point
+/// label.
+///
+/// ```
+/// use unicode_charname::NamedChar;
+///
+/// assert_eq!('A'.name(), "LATIN CAPITAL LETTER A");
+/// assert_eq!('\t'.name(), "<control-0009>");
+/// ```
+pub trait NamedChar {
+    /// Returns the name of the character, like [`CharName::char_name`]
+    /// without the `Option`.
+    fn name(self) -> Name;
+}
+
+impl NamedChar for char {
+    fn name(self) -> Name {
+        self.char_name()
+            .expect("characters always have a name or a label")
+    }
+}
+
+/// An iterator over the characters of a string and their names, see
+/// [`StrCharNames::char_names`].
+#[derive(Clone)]
+pub struct CharNames<'a> {
+    chars: std::str::Chars<'a>,
+}
+
+impl Iterator for CharNames<'_> {
+    type Item = (char, Name);
+
+    fn next(&mut self) -> Option<(char, Name)> {
+        let c = self.chars.next()?;
+        Some((c, c.name()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chars.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for CharNames<'_> {
+    fn next_back(&mut self) -> Option<(char, Name)> {
+        let c = self.chars.next_back()?;
+        Some((c, c.name()))
+    }
+}
+
+/// An iterator over the characters of a string, their byte offsets and
+/// their names, see [`StrCharNames::char_indices_names`].
+#[derive(Clone)]
+pub struct CharIndicesNames<'a> {
+    char_indices: std::str::CharIndices<'a>,
+}
+
+impl Iterator for CharIndicesNames<'_> {
+    type Item = (usize, char, Name);
+
+    fn next(&mut self) -> Option<(usize, char, Name)> {
+        let (offset, c) = self.char_indices.next()?;
+        Some((offset, c, c.name()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.char_indices.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for CharIndicesNames<'_> {
+    fn next_back(&mut self) -> Option<(usize, char, Name)> {
+        let (offset, c) = self.char_indices.next_back()?;
+        Some((offset, c, c.name()))
+    }
+}
+
+/// Returns the name of the code point `cp`, or its label like
+/// `<control-0009>` when it has no name.
+///
+/// This is the canonical lookup, [`CharName::char_name`] forwards to it.
+/// Returns `None` for values above U+10FFFF.
+///
+/// ```
+/// assert_eq!(
+///     unicode_charname::name(0x41).unwrap(),
+///     "LATIN CAPITAL LETTER A"
+/// );
+/// assert_eq!(unicode_charname::name(0x9).unwrap(), "<control-0009>");
+/// assert!(unicode_charname::name(0x110000).is_none());
+/// ```
+pub fn name(cp: u32) -> Option<Name> {
+    CharName::property_name(cp).or_else(|| code_point_label(cp, LabelStyle::BRACKETED))
+}
+
+/// Returns the label of the code point `cp` without angle brackets, like
+/// `control-0009`, or `None` when it has a name.
+///
+/// [`CharName::char_label`] forwards to it.
+///
+/// ```
+/// assert_eq!(unicode_charname::label(0x9).unwrap(), "control-0009");
+/// assert!(unicode_charname::label(0x41).is_none());
+/// ```
+pub fn label(cp: u32) -> Option<Name> {
+    code_point_label(cp, LabelStyle::BARE)
+}
+
+/// Returns the label of the code point `cp` in the given style, or `None`
+/// when it has a name or is above U+10FFFF.
+///
+/// ```
+/// use unicode_charname::{code_point_label, LabelStyle};
+///
+/// let style = LabelStyle {
+///     angle_brackets: false,
+///     uppercase: true,
+/// };
+/// assert_eq!(code_point_label(0x9, style).unwrap(), "CONTROL-0009");
+/// assert!(code_point_label(0x41, style).is_none());
+/// ```
+pub fn code_point_label(cp: u32, style: LabelStyle) -> Option<Name> {
+    let kind = match classify(cp)? {
+        Class::Named(_) => return None,
+        Class::SpecialGroup(special_group) => match special_group_kind(special_group) {
+            GeneratedKind::Label(kind) => kind,
+            _ => return None,
+        },
+        Class::Unassigned(kind) => kind,
+    };
+    Some(label_name(kind, cp, style))
+}
+
+/// Returns whether `v` is a code point, from U+0000 to U+10FFFF.
+pub fn is_code_point(v: u32) -> bool {
+    reserved::is_code_point(v)
+}
+
+/// Returns the ranges of code points named by rule in UnicodeData.txt,
+/// like `<CJK Ideograph, First>` to `<CJK Ideograph, Last>`, along with
+/// their labels.
+#[cfg(test)]
+pub(crate) fn special_group_ranges() -> impl Iterator<Item = (RangeInclusive<u32>, &'static str)> {
+    tables::SPECIAL_GROUPS
+        .iter()
+        .map(|&(first, last, group)| (first..=last, group.label()))
+}
+
+/// Returns the kind of the surrogate code point `cp`, from the ranges of
+/// UnicodeData.txt.
+///
+/// Returns `None` outside U+D800 to U+DFFF.
+pub fn surrogate_kind(cp: u32) -> Option<SurrogateKind> {
+    use tables::SpecialGroup;
+    match tables::find_in_special_groups(cp)? {
+        SpecialGroup::NonPrivateUseHighSurrogate => Some(SurrogateKind::High),
+        SpecialGroup::PrivateUseHighSurrogate => Some(SurrogateKind::PrivateUseHigh),
+        SpecialGroup::LowSurrogate => Some(SurrogateKind::Low),
+        _ => None,
+    }
+}
+
+/// Returns whether `v` is one of the 66 noncharacters: U+FDD0 to U+FDEF,
+/// and the last two code points of each plane.
+pub fn is_noncharacter(v: u32) -> bool {
+    reserved::is_noncharacter(v)
+}
+
+/// Returns whether the code point `v` is assigned: whether it is a graphic,
+/// format, control, private-use or surrogate code point, rather than a
+/// noncharacter or a reserved one.
+///
+/// It's `false` exactly when [`CharName::char_name`] gives a
+/// `<noncharacter-...>` or `<reserved-...>` label, or `None`.
+pub fn is_assigned(v: u32) -> bool {
+    match v.code_point_type() {
+        Some(CodePointType::Noncharacter) | Some(CodePointType::Reserved) | None => false,
+        Some(_) => true,
+    }
+}
+
+/// Returns the CLDR English short name of the emoji `s`, like "flag: Japan"
+/// for "\u{1F1EF}\u{1F1F5}", from emoji-test.txt of [`EMOJI_VERSION`].
+///
+/// Works for sequences without a Unicode name too, like keycaps, flags,
+/// skin tone modifiers and ZWJ sequences. Returns `None` unless `s` is
+/// exactly one emoji.
+#[cfg(feature = "emoji-names")]
+pub fn emoji_short_name(s: &str) -> Option<&'static str> {
+    tables::find_emoji_name(s)
+}
+
+/// Returns the CLDR English short name of the emoji `c`, see
+/// [`emoji_short_name`].
+///
+/// Characters like U+263A that need U+FE0F VARIATION SELECTOR-16 to be
+/// shown as emoji are looked up along with it.
+#[cfg(feature = "emoji-names")]
+pub fn char_emoji_short_name(c: char) -> Option<&'static str> {
+    let mut buf = [0; 8];
+    let len = c.encode_utf8(&mut buf).len();
+    let len = len + '\u{FE0F}'.encode_utf8(&mut buf[len..]).len();
+    let with_selector = std::str::from_utf8(&buf[..len]).expect("valid UTF-8");
+    emoji_short_name(&with_selector[..c.len_utf8()]).or_else(|| emoji_short_name(with_selector))
+}
+
+/// Displays `c` for error messages, like
+/// `'é' (U+00E9 LATIN SMALL LETTER E WITH ACUTE)`, see [`DisplayWithName`].
+pub fn annotate(c: char) -> DisplayWithName {
+    DisplayWithName(c)
+}
+
+/// Describes the characters of `s`, one line per character like
+/// `U+0065 e LATIN SMALL LETTER E`.
+///
+/// Characters that aren't visible on their own, like controls and
+/// whitespace, are shown as `\u{XXXX}` escapes. Nothing is collected up
+/// front, so this works on large strings.
+///
+/// ```
+/// assert_eq!(
+///     unicode_charname::describe("a\t").to_string(),
+///     "U+0061 a LATIN SMALL LETTER A\nU+0009 \\u{0009} <control-0009>\n"
+/// );
+/// ```
+pub fn describe(s: &str) -> Describe<'_> {
+    Describe { s }
+}
+
+/// Returns the character named `name`, matching exactly, like
+/// `unicodedata.lookup` in Python.
+///
+/// Finds the names listed in UnicodeData.txt and the names derived by
+/// rule, like "HANGUL SYLLABLE GAG" and "CJK UNIFIED IDEOGRAPH-4E00", the
+/// inverse of [`CharName::property_name`], as well as the formal aliases
+/// from NameAliases.txt, like "ZWJ" and "NUL". See [`lookup_with_aliases`]
+/// to choose which kinds of aliases are considered.
+///
+/// The names listed in UnicodeData.txt are found by binary search, or with
+/// the `phf` feature, by a perfect hash built by the table generator.
+///
+/// ```
+/// assert_eq!(unicode_charname::lookup("OX"), Some('\u{1F402}'));
+/// assert_eq!(unicode_charname::lookup("ox"), None);
+/// assert_eq!(unicode_charname::lookup("HANGUL SYLLABLE GAG"), Some('\u{AC01}'));
+/// assert_eq!(unicode_charname::lookup("ZWJ"), Some('\u{200D}'));
+/// ```
+pub fn lookup(name: &str) -> Option<char> {
+    lookup_with_aliases(name, AliasKind::ALL)
+}
+
+/// Returns the character named `name`, like [`lookup`], but only considers
+/// the aliases of the given `kinds`.
+///
+/// Names take precedence over aliases, so an empty `kinds` finds the same
+/// characters as [`CharName::property_name`] names.
+///
+/// ```
+/// use unicode_charname::{lookup_with_aliases, AliasKind};
+///
+/// let kinds = &[AliasKind::Correction, AliasKind::Control, AliasKind::Abbreviation];
+/// assert_eq!(lookup_with_aliases("LINE FEED", kinds), Some('\n'));
+/// // U+0099 has no Name, only a figment alias.
+/// assert_eq!(lookup_with_aliases("SINGLE GRAPHIC CHARACTER INTRODUCER", kinds), None);
+/// ```
+pub fn lookup_with_aliases(name: &str, kinds: &[AliasKind]) -> Option<char> {
+    if let Some(c) = lookup_name(name) {
+        return Some(c);
+    }
+    let (kind, v) = tables::find_alias(name)?;
+    if !kinds.contains(&kind) {
+        return None;
+    }
+    std::char::from_u32(v)
+}
+
+fn lookup_name(name: &str) -> Option<char> {
+    if name.len() > NAME_MAX_LEN {
+        return None;
+    }
+    if let Some(rest) = name.strip_prefix("HANGUL SYLLABLE ") {
+        return jamo::hangul_syllable_from_name(rest).and_then(std::char::from_u32);
+    }
+    for &prefix in Nr2Prefix::ALL {
+        if let Some(hex) = name.strip_prefix(prefix.as_str()) {
+            return nr2_code_point(prefix, hex).and_then(std::char::from_u32);
+        }
+    }
+    #[cfg(feature = "phf")]
+    return lookup_listed_name_phf(name);
+    #[cfg(not(feature = "phf"))]
+    return lookup_listed_name(name);
+}
+
+/// Returns the character with the name `name` listed in UnicodeData.txt,
+/// by binary search, as [`lookup`] does without the `phf` feature.
+#[cfg(any(test, not(feature = "phf")))]
+pub(crate) fn lookup_listed_name(name: &str) -> Option<char> {
+    let record_idx = tables::NAMES_BY_NAME
+        .binary_search_by(|&v| {
+            let candidate = CharName::property_name(v).expect("the name tables have names");
+            candidate.bytes().cmp(name.bytes())
+        })
+        .ok()?;
+    std::char::from_u32(tables::NAMES_BY_NAME[record_idx])
+}
+
+/// Returns the character with the name `name` listed in UnicodeData.txt,
+/// by the perfect hash of the names built by the table generator.
+#[cfg(feature = "phf")]
+fn lookup_listed_name_phf(name: &str) -> Option<char> {
+    let v = tables::phf_name_candidate(name);
+    let candidate = CharName::property_name(v).expect("the name tables have names");
+    if !candidate.bytes().eq(name.bytes()) {
+        return None;
+    }
+    std::char::from_u32(v)
+}
+
+/// Returns the character named `name` ignoring ASCII case, but otherwise
+/// matching exactly like [`lookup`], aliases included.
+///
+/// Unlike [`lookup_loose`], spaces and hyphens must be just as in the
+/// name.
+///
+/// ```
+/// use unicode_charname::lookup_ignore_case;
+///
+/// assert_eq!(lookup_ignore_case("zero width joiner"), Some('\u{200D}'));
+/// assert_eq!(lookup_ignore_case("ZERO-WIDTH JOINER"), None);
+/// ```
+pub fn lookup_ignore_case(name: &str) -> Option<char> {
+    let mut buf = [0; NAME_MAX_LEN];
+    let buf = buf.get_mut(..name.len())?;
+    buf.copy_from_slice(name.as_bytes());
+    // names are ASCII, so uppercasing leaves other characters as they are.
+    buf.make_ascii_uppercase();
+    lookup(std::str::from_utf8(buf).ok()?)
+}
+
+/// Returns the character named `name` under the loose matching rule
+/// UAX44-LM2: case, whitespace, underscores and medial hyphens are ignored,
+/// except for the hyphen of U+1180 HANGUL JUNGSEONG O-E.
+///
+/// Like [`lookup`], this finds the names derived by rule and the aliases
+/// too, with names taking precedence.
+///
+/// ```
+/// use unicode_charname::lookup_loose;
+///
+/// assert_eq!(lookup_loose("zero width joiner"), Some('\u{200D}'));
+/// assert_eq!(lookup_loose("ZERO-WIDTH_JOINER"), Some('\u{200D}'));
+/// assert_eq!(lookup_loose("cjk unified ideograph 4e00"), Some('\u{4E00}'));
+/// assert_eq!(lookup_loose("line feed"), Some('\n'));
+/// ```
+pub fn lookup_loose(name: &str) -> Option<char> {
+    let mut buf = [0; NAME_MAX_LEN];
+    let key = loose_key(name, &mut buf)?;
+    if let Some(rest) = key.strip_prefix("HANGULSYLLABLE") {
+        if let Some(v) = jamo::hangul_syllable_from_name(rest) {
+            return std::char::from_u32(v);
+        }
+    }
+    for &prefix in Nr2Prefix::ALL {
+        if let Some(hex) = key.strip_prefix(prefix.loose_key()) {
+            if let Some(v) = nr2_code_point(prefix, hex) {
+                return std::char::from_u32(v);
+            }
+        }
+    }
+    let found = tables::NAMES_BY_LOOSE_NAME.binary_search_by(|&v| {
+        let candidate = CharName::property_name(v).expect("the name tables have names");
+        let keep_medial_hyphens = is_loose_jungseong_o_e(candidate.chars());
+        LooseChars::new(candidate.chars(), keep_medial_hyphens)
+            .cmp(key.chars().map(|c| c.to_ascii_lowercase()))
+    });
+    if let Ok(record_idx) = found {
+        return std::char::from_u32(tables::NAMES_BY_LOOSE_NAME[record_idx]);
+    }
+    // names take precedence over aliases, as in `lookup`.
+    let record_idx = tables::NAME_ALIASES_BY_LOOSE_ALIAS
+        .binary_search_by(|&idx| {
+            let (candidate, _, _) = tables::NAME_ALIASES_BY_ALIAS[usize::from(idx)];
+            LooseChars::new(candidate.chars(), false)
+                .cmp(key.chars().map(|c| c.to_ascii_lowercase()))
+        })
+        .ok()?;
+    let idx = tables::NAME_ALIASES_BY_LOOSE_ALIAS[record_idx];
+    std::char::from_u32(tables::NAME_ALIASES_BY_ALIAS[usize::from(idx)].2)
+}
+
+/// Writes the UAX44-LM2 key of `name` into `buf` in uppercase, the case of
+/// the names, or returns `None` if no name has that key.
+fn loose_key<'a>(name: &str, buf: &'a mut [u8; NAME_MAX_LEN]) -> Option<&'a str> {
+    let keep_medial_hyphens = is_loose_jungseong_o_e(name.chars());
+    let mut len = 0;
+    for c in LooseChars::new(name.chars(), keep_medial_hyphens) {
+        // names are ASCII.
+        if !c.is_ascii() {
+            return None;
+        }
+        *buf.get_mut(len)? = c.to_ascii_uppercase() as u8;
+        len += 1;
+    }
+    std::str::from_utf8(&buf[..len]).ok()
+}
+
+/// Returns the name of the named sequence `s`, like "KEYCAP DIGIT ONE" for
+/// "1\u{FE0F}\u{20E3}", from NamedSequences.txt.
+///
+/// Returns `None` unless `s` is exactly one of the sequences.
+pub fn sequence_name(s: &str) -> Option<&'static str> {
+    tables::NAMED_SEQUENCES
+        .iter()
+        .find(|&&(_, sequence)| sequence == s)
+        .map(|&(name, _)| name)
+}
+
+/// Returns the characters of the named sequence called `name`, the
+/// inverse of [`sequence_name`].
+///
+/// This is the same as [`lookup_sequence`].
+pub fn named_sequence(name: &str) -> Option<&'static str> {
+    lookup_sequence(name)
+}
+
+/// Returns the characters of the named sequence called `name`, matching
+/// exactly, like `unicodedata.lookup` in Python does for sequences.
+///
+/// ```
+/// assert_eq!(
+///     unicode_charname::lookup_sequence("KEYCAP NUMBER SIGN"),
+///     Some("#\u{FE0F}\u{20E3}")
+/// );
+/// ```
+pub fn lookup_sequence(name: &str) -> Option<&'static str> {
+    tables::find_named_sequence(name)
+}
+
+/// Returns the characters of the named sequence called `name` under the
+/// loose matching rule UAX44-LM2, see [`lookup_loose`].
+pub fn lookup_sequence_loose(name: &str) -> Option<&'static str> {
+    let mut buf = [0; NAME_MAX_LEN];
+    let key = loose_key(name, &mut buf)?;
+    let record_idx = tables::NAMED_SEQUENCES_BY_LOOSE_NAME
+        .binary_search_by(|&idx| {
+            let (candidate, _) = tables::NAMED_SEQUENCES[usize::from(idx)];
+            LooseChars::new(candidate.chars(), false)
+                .cmp(key.chars().map(|c| c.to_ascii_lowercase()))
+        })
+        .ok()?;
+    let idx = tables::NAMED_SEQUENCES_BY_LOOSE_NAME[record_idx];
+    Some(tables::NAMED_SEQUENCES[usize::from(idx)].1)
+}
+
+/// What a name stands for, see [`lookup_any`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LookupResult {
+    /// The name of a character, or one of its aliases.
+    Char(char),
+    /// The name of a named sequence, with its characters.
+    Sequence(&'static str),
+}
+
+/// Returns the character or the named sequence called `name`, matching
+/// exactly, see [`lookup`] and [`lookup_sequence`].
+///
+/// ```
+/// use unicode_charname::{lookup_any, LookupResult};
+///
+/// assert_eq!(lookup_any("NBSP"), Some(LookupResult::Char('\u{A0}')));
+/// assert_eq!(
+///     lookup_any("TAMIL CONSONANT K"),
+///     Some(LookupResult::Sequence("\u{B95}\u{BCD}"))
+/// );
+/// ```
+pub fn lookup_any(name: &str) -> Option<LookupResult> {
+    lookup(name)
+        .map(LookupResult::Char)
+        .or_else(|| lookup_sequence(name).map(LookupResult::Sequence))
+}
+
+/// Returns the character or the named sequence called `name` under the
+/// loose matching rule UAX44-LM2, see [`lookup_loose`] and
+/// [`lookup_sequence_loose`].
+pub fn lookup_any_loose(name: &str) -> Option<LookupResult> {
+    lookup_loose(name)
+        .map(LookupResult::Char)
+        .or_else(|| lookup_sequence_loose(name).map(LookupResult::Sequence))
+}
+
+/// Returns the character whose Unicode 1.0 name is `name`, matching
+/// exactly, the inverse of [`CharName::unicode_1_0_name`].
+///
+/// Some of these names have since been given to other characters, so this
+/// is separate from [`lookup`], where current names always win.
+///
+/// ```
+/// use unicode_charname::{lookup, lookup_unicode_1_0_name};
+///
+/// assert_eq!(lookup_unicode_1_0_name("BELL"), Some('\u{7}'));
+/// assert_eq!(lookup("BELL"), Some('\u{1F514}'));
+/// ```
+pub fn lookup_unicode_1_0_name(name: &str) -> Option<char> {
+    tables::find_by_unicode_1_name(name).and_then(std::char::from_u32)
+}
+
+/// Returns the characters whose names start with `prefix`, with their
+/// names, in the order of the names.
+///
+/// Only the names listed in UnicodeData.txt are searched, not the names
+/// derived by rule like "HANGUL SYLLABLE GAG" and
+/// "CJK UNIFIED IDEOGRAPH-4E00". The matching names are found by binary
+/// search, and produced lazily.
+///
+/// ```
+/// let mut names = unicode_charname::names_with_prefix("BOX DRAWINGS LIGHT DOWN AND");
+/// let (c, name) = names.next().unwrap();
+/// assert_eq!(c, '\u{252C}');
+/// assert_eq!(name, "BOX DRAWINGS LIGHT DOWN AND HORIZONTAL");
+/// ```
+pub fn names_with_prefix(prefix: &str) -> impl Iterator<Item = (char, Name)> {
+    names_in(name_prefix_range(prefix, false))
+}
+
+/// Returns the characters whose names start with `prefix`, ignoring ASCII
+/// case, see [`names_with_prefix`].
+pub fn names_with_prefix_ignore_case(prefix: &str) -> impl Iterator<Item = (char, Name)> {
+    names_in(name_prefix_range(prefix, true))
+}
+
+/// Returns the characters within `range` whose names start with `prefix`,
+/// see [`names_with_prefix`].
+///
+/// Of the names found by binary search, only those in `range` are kept,
+/// without looking at the names of the other code points.
+pub fn names_with_prefix_in(
+    prefix: &str,
+    range: RangeInclusive<u32>,
+) -> impl Iterator<Item = (char, Name)> {
+    let names = &tables::NAMES_BY_NAME[name_prefix_range(prefix, false)];
+    names
+        .iter()
+        .filter(move |v| range.contains(v))
+        .map(|&v| named_table_entry(v))
+}
+
+/// Returns the range of `NAMES_BY_NAME` whose names start with `prefix`.
+fn name_prefix_range(prefix: &str, ignore_case: bool) -> Range<usize> {
+    let prefix_bytes = || {
+        prefix.bytes().map(move |b| {
+            if ignore_case {
+                b.to_ascii_uppercase()
+            } else {
+                b
+            }
+        })
+    };
+    let cmp = |v: u32| {
+        let name = CharName::property_name(v).expect("the name tables have names");
+        name.bytes().take(prefix.len()).cmp(prefix_bytes())
+    };
+    let start = tables::NAMES_BY_NAME.partition_point(|&v| cmp(v) == Ordering::Less);
+    let end = tables::NAMES_BY_NAME.partition_point(|&v| cmp(v) != Ordering::Greater);
+    start..end
+}
+
+fn names_in(range: Range<usize>) -> impl Iterator<Item = (char, Name)> {
+    tables::NAMES_BY_NAME[range]
+        .iter()
+        .map(|&v| named_table_entry(v))
+}
+
+/// Returns the characters whose names contain `word` as a whole word, with
+/// their names, in code point order, see [`Name::contains_word`].
+///
+/// Only whole words match, so "ARROW" doesn't find "UPWARDS ARROWHEAD".
+/// The word is resolved to its index in the word table of the names once,
+/// and a word that no name uses finds nothing right away. Like
+/// [`names_with_prefix`], only the names listed in UnicodeData.txt are
+/// searched.
+///
+/// ```
+/// let mut names = unicode_charname::names_with_word("ARROW");
+/// assert_eq!(names.next().unwrap().1, "MODIFIER LETTER LOW LEFT ARROW");
+/// assert_eq!(unicode_charname::names_with_word("ARROWZ").count(), 0);
+/// ```
+pub fn names_with_word(word: &str) -> impl Iterator<Item = (char, Name)> {
+    names_with_word_in(word, 0..=0x10_FFFF)
+}
+
+/// Returns the characters within `range` whose names contain `word` as a
+/// whole word, see [`names_with_word`].
+///
+/// Only the parts of the name tables covering `range` are searched.
+///
+/// ```
+/// let mut names = unicode_charname::names_with_word_in("ARROW", 0x2190..=0x21FF);
+/// assert_eq!(names.next().unwrap().1, "LEFTWARDS ARROW");
+/// ```
+pub fn names_with_word_in(
+    word: &str,
+    range: RangeInclusive<u32>,
+) -> impl Iterator<Item = (char, Name)> {
+    let word_idx = find_word_index(word);
+    // the code points in names like "CJK COMPATIBILITY IDEOGRAPH-F900" are
+    // not in the word table.
+    let code_point = u32::from_str_radix(word, 16)
+        .ok()
+        .filter(|&v| reserved::is_code_point(v) && hex_repr(v).as_str() == word);
+    let range = match (word_idx, code_point) {
+        (Some(_), _) => Some(range),
+        (None, Some(v)) if range.contains(&v) => Some(v..=v),
+        (None, _) => None,
+    };
+    range
+        .into_iter()
+        .flat_map(encoded_names_in)
+        .filter_map(move |(v, encoded_slice)| {
+            let found = word_idx.is_some_and(|word_idx| encoded_slice.contains(&word_idx))
+                || (code_point == Some(v)
+                    && encoded_slice.contains(&tables::WORD_TABLE_INDEX_CODEPOINT));
+            if !found {
+                return None;
+            }
+            Some(named_table_entry(v))
+        })
+}
+
+/// Returns the characters whose names contain `needle`, with their names,
+/// in code point order, see [`Name::contains`].
+///
+/// Like [`names_with_prefix`], only the names listed in UnicodeData.txt are
+/// searched.
+///
+/// ```
+/// let snowmen: String = unicode_charname::names_containing("SNOWMAN")
+///     .map(|(c, _)| c)
+///     .collect();
+/// assert_eq!(snowmen, "\u{2603}\u{26C4}\u{26C7}");
+/// ```
+pub fn names_containing(needle: &str) -> impl Iterator<Item = (char, Name)> + '_ {
+    names_containing_in(needle, 0..=0x10_FFFF)
+}
+
+/// Returns the characters within `range` whose names contain `needle`, see
+/// [`names_containing`].
+///
+/// Only the parts of the name tables covering `range` are searched.
+pub fn names_containing_in(
+    needle: &str,
+    range: RangeInclusive<u32>,
+) -> impl Iterator<Item = (char, Name)> + '_ {
+    encoded_names_in(range).filter_map(move |(v, _)| {
+        let name = CharName::property_name(v).expect("the name tables have names");
+        if !name.contains(needle) {
+            return None;
+        }
+        let c = std::char::from_u32(v).expect("the name tables have characters");
+        Some((c, name))
+    })
+}
+
+/// Returns the character `v` of the name tables with its name.
+fn named_table_entry(v: u32) -> (char, Name) {
+    let c = std::char::from_u32(v).expect("the name tables have characters");
+    let name = CharName::property_name(v).expect("the name tables have names");
+    (c, name)
+}
+
+/// Returns the code points of the name tables within `range`, with their
+/// encoded names, skipping the records of the tables outside it.
+fn encoded_names_in(range: RangeInclusive<u32>) -> impl Iterator<Item = (u32, &'static [u16])> {
+    let (start, end) = (*range.start(), *range.end());
+    let first_record =
+        tables::ENUMERATION_CHAR_NAMES.partition_point(|&(_, last, _, _)| last < start);
+    tables::ENUMERATION_CHAR_NAMES[first_record..]
+        .iter()
+        .take_while(move |&&(first, _, _, _)| first <= end)
+        .flat_map(move |&(first, last, index_slice, offset_slice)| {
+            (first.max(start)..=last.min(end)).map(move |v| {
+                let offset = (v - first) as usize;
+                let range = (offset_slice[offset] as usize)..(offset_slice[offset + 1] as usize);
+                (v, &index_slice[range])
+            })
+        })
+}
+
+/// Which code points a [`search`] looks at.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Filter<'a> {
+    /// All code points.
+    All,
+    /// The code points in a range.
+    Range(RangeInclusive<u32>),
+    /// The code points of a block, given by a name from Blocks.txt like
+    /// "Braille Patterns", matched like [`Block::from_name`].
+    Block(&'a str),
+}
+
+impl Filter<'_> {
+    /// Returns the code points the filter lets through.
+    pub fn range(&self) -> Result<RangeInclusive<u32>, UnknownBlockError> {
+        match self {
+            Filter::All => Ok(0..=0x10_FFFF),
+            Filter::Range(range) => Ok(range.clone()),
+            Filter::Block(name) => Block::from_name(name)
+                .map(|block| block.range())
+                .ok_or_else(|| UnknownBlockError {
+                    name: name.to_string(),
+                }),
+        }
+    }
+}
+
+/// Returns the characters let through by `filter` whose names contain
+/// `needle`, see [`names_containing_in`].
+///
+/// The other range-restricted searches take the range from
+/// [`Filter::range`].
+///
+/// ```
+/// use unicode_charname::{search, Filter};
+///
+/// let dots = search("DOTS-1", Filter::Block("braille_patterns")).unwrap();
+/// assert_eq!(dots.count(), 128);
+/// assert!(search("DOT", Filter::Block("Brail Patterns")).is_err());
+/// ```
+pub fn search<'a>(
+    needle: &'a str,
+    filter: Filter<'_>,
+) -> Result<impl Iterator<Item = (char, Name)> + 'a, UnknownBlockError> {
+    Ok(names_containing_in(needle, filter.range()?))
+}
+
+/// Returns the number of code points the range-restricted searches like
+/// [`names_containing_in`] visit for `range`.
+#[cfg(test)]
+pub(crate) fn search_candidate_count(range: RangeInclusive<u32>) -> usize {
+    encoded_names_in(range).count()
+}
+
+/// Displays the name of the code point `cp` without allocating, like
+/// `<invalid-00110000>` for values above U+10FFFF.
+///
+/// ```
+/// let message = format!("got {}", unicode_charname::char_name_display(0x7F));
+/// assert_eq!(message, "got <control-007F>");
+/// ```
+pub fn char_name_display(cp: u32) -> CharNameDisplay {
+    CharNameDisplay { code_point: cp }
+}
+
+/// Displays the code point `cp` in the standard notation, like `U+0041` or
+/// `U+1F402`: "U+" followed by at least four uppercase hexadecimal digits.
+///
+/// ```
+/// assert_eq!(unicode_charname::format_codepoint(0x1F402).to_string(), "U+1F402");
+/// ```
+pub fn format_codepoint(cp: u32) -> CodePointNotation {
+    CodePointNotation { code_point: cp }
+}
+
+/// Returns the code point with the longest name, along with that name,
+/// which is [`NAME_MAX_LEN`] bytes long.
+pub fn longest_name() -> (u32, Name) {
+    let v = tables::NAME_MAX_LEN_CODEPOINT;
+    let name = name(v).expect("the longest name belongs to a code point");
+    (v, name)
+}
+
+fn hex_repr(v: u32) -> InlineStr<6> {
+    let mut repr = InlineStr::new();
+    write!(repr, "{:04X}", v).expect("code points have at most 6 hex digits");
+    repr
+}
+
+// writing generated names into a `SmallString` never fails.
+
+fn nr1_name(_prefix: &str, v: u32) -> Name {
+    // ignore prefix here, because hangul_name will provide one.
+    let mut text = SmallString::new();
+    let _ = jamo::write_hangul_name(&mut text, v);
+    Name(NameInner::Generated {
+        kind: GeneratedKind::HangulSyllable,
+        code_point: PackedCodePoint::some(v),
+        text,
+    })
+}
+
+fn nr2_name(prefix: Nr2Prefix, v: u32) -> Name {
+    let mut text = SmallString::new();
+    let _ = write!(text, "{}{:04X}", prefix.as_str(), v);
+    Name(NameInner::Generated {
+        kind: GeneratedKind::Nr2(prefix),
+        code_point: PackedCodePoint::some(v),
+        text,
+    })
+}
+
+/// Returns the bytes of a block name compared by [`Block::from_name`].
+fn loose_block_name(name: &str) -> impl Iterator<Item = u8> + '_ {
+    name.bytes()
+        .filter(|b| !matches!(b, b' ' | b'_' | b'-'))
+        .map(|b| b.to_ascii_lowercase())
+}
+
+/// Returns the name of a block as spelled by Java's `Character.UnicodeBlock`,
+/// with spaces for underscores.
+fn java_block_name(name: &str) -> String {
+    // the constants named before Unicode 4.0 renamed their blocks.
+    match name {
+        "Greek and Coptic" => "GREEK".to_owned(),
+        "Cyrillic Supplement" => "CYRILLIC SUPPLEMENTARY".to_owned(),
+        "Combining Diacritical Marks for Symbols" => "COMBINING MARKS FOR SYMBOLS".to_owned(),
+        _ => name.to_ascii_uppercase().replace('-', " "),
+    }
+}
+
+fn block_label_name(v: u32, block: Block) -> Name {
+    let mut text = SmallString::new();
+    let _ = write!(
+        text,
+        "{}{:04X} ({})",
+        LabelKind::Reserved.prefix(),
+        v,
+        block.name()
+    );
+    Name(NameInner::Generated {
+        kind: GeneratedKind::Label(LabelKind::Reserved),
+        code_point: PackedCodePoint::some(v),
+        text,
+    })
+}
+
+/// Returns the code point named `prefix` followed by `hex`, the inverse of
+/// `nr2_name`.
+fn nr2_code_point(prefix: Nr2Prefix, hex: &str) -> Option<u32> {
+    let v = u32::from_str_radix(hex, 16)
+        .ok()
+        .filter(|&v| reserved::is_code_point(v))?;
+    // only the digits `nr2_name` writes, without signs or extra zeros.
+    if hex_repr(v).as_str() != hex {
+        return None;
+    }
+    match classify(v)? {
+        Class::SpecialGroup(special_group) => match special_group_kind(special_group) {
+            GeneratedKind::Nr2(group_prefix) if group_prefix == prefix => Some(v),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn alias_name(v: u32, alias: &'static str) -> Name {
+    Name(NameInner::Generated {
+        kind: GeneratedKind::Explicit,
+        code_point: PackedCodePoint::some(v),
+        text: SmallString::Static(alias),
+    })
+}
+
+/// Returns the latest correction alias of `v`, if its name was corrected.
+fn correction_alias(v: u32) -> Option<&'static str> {
+    // the latest correction comes last.
+    tables::find_name_aliases(v)
+        .iter()
+        .rev()
+        .find(|&&(kind, _)| kind == AliasKind::Correction)
+        .map(|&(_, alias)| alias)
+}
+
+/// Where the name of a code point comes from.
+enum Class {
+    /// The name tables.
+    Named(&'static [u16]),
+    /// The ranges of UnicodeData.txt, like `<CJK Ideograph, First>`.
+    SpecialGroup(tables::SpecialGroup),
+    /// Neither, for noncharacters and reserved code points.
+    Unassigned(LabelKind),
+}
+
+fn classify(v: u32) -> Option<Class> {
+    if !reserved::is_code_point(v) {
+        return None;
+    }
+    // noncharacters come first, so that no table can capture them.
+    if reserved::is_noncharacter(v) {
+        return Some(Class::Unassigned(LabelKind::Noncharacter));
+    }
+    if let Some(slice) = tables::find_in_enumerate_names(v) {
+        return Some(Class::Named(slice));
+    }
+    if let Some(special_group) = tables::find_in_special_groups(v) {
+        return Some(Class::SpecialGroup(special_group));
+    }
+    Some(Class::Unassigned(LabelKind::Reserved))
+}
+
+/// Returns the label kind of code points outside the name tables.
+fn unassigned_label_kind(v: u32) -> Option<LabelKind> {
+    if !reserved::is_code_point(v) {
+        return None;
+    }
+    if reserved::is_noncharacter(v) {
+        Some(LabelKind::Noncharacter)
+    } else {
+        Some(LabelKind::Reserved)
+    }
+}
+
+fn unassigned_code_point_label(v: u32, style: LabelStyle) -> Option<Name> {
+    let kind = unassigned_label_kind(v)?;
+    Some(label_name(kind, v, style))
+}
+
+fn label_name(kind: LabelKind, v: u32, style: LabelStyle) -> Name {
+    let mut text = SmallString::new();
+    if style.angle_brackets {
+        let _ = text.write_char('<');
+    }
+    for c in kind.prefix().chars() {
+        let c = if style.uppercase {
+            c.to_ascii_uppercase()
+        } else {
+            c
+        };
+        let _ = text.write_char(c);
+    }
+    let _ = write!(text, "{:04X}", v);
+    if style.angle_brackets {
+        let _ = text.write_char('>');
+    }
+    Name(NameInner::Generated {
+        kind: GeneratedKind::Label(kind),
+        code_point: PackedCodePoint::some(v),
+        text,
+    })
+}
+
+/// Returns how the names of the characters in the group are derived.
+fn special_group_kind(special_group: tables::SpecialGroup) -> GeneratedKind {
+    use tables::SpecialGroup;
+    match special_group {
+        // NR1
+        SpecialGroup::HangulSyllable => GeneratedKind::HangulSyllable,
+        // NR2
+        SpecialGroup::CJKIdeographExtensionA
+        | SpecialGroup::CJKIdeograph
+        | SpecialGroup::CJKIdeographExtensionB
+        | SpecialGroup::CJKIdeographExtensionC
+        | SpecialGroup::CJKIdeographExtensionD
+        | SpecialGroup::CJKIdeographExtensionE
+        | SpecialGroup::CJKIdeographExtensionF
+        | SpecialGroup::CJKIdeographExtensionG => {
+            GeneratedKind::Nr2(Nr2Prefix::CjkUnifiedIdeograph)
+        }
+        SpecialGroup::TangutIdeograph | SpecialGroup::TangutIdeographSupplement => {
+            GeneratedKind::Nr2(Nr2Prefix::TangutIdeograph)
+        }
+        /* other NR2 cases already covered in UnicodeData.txt */
+        SpecialGroup::control => GeneratedKind::Label(LabelKind::Control),
+        SpecialGroup::NonPrivateUseHighSurrogate
+        | SpecialGroup::PrivateUseHighSurrogate
+        | SpecialGroup::LowSurrogate => GeneratedKind::Label(LabelKind::Surrogate),
+        SpecialGroup::PrivateUse
+        | SpecialGroup::Plane15PrivateUse
+        | SpecialGroup::Plane16PrivateUse => GeneratedKind::Label(LabelKind::PrivateUse),
+    }
+}
+
+/// Returns the NR1 or NR2 name of a character in the group, labels are
+/// left to [`code_point_label`].
+fn name_for_special_group_char(v: u32, special_group: tables::SpecialGroup) -> Option<Name> {
+    match special_group_kind(special_group) {
+        GeneratedKind::HangulSyllable => Some(nr1_name("HANGUL SYLLABLE ", v)),
+        GeneratedKind::Nr2(prefix) => Some(nr2_name(prefix, v)),
+        GeneratedKind::Label(_) | GeneratedKind::Explicit => None,
+    }
+}
+
+#[derive(Clone)]
+enum NameInner {
+    Enumeration {
+        encoded_slice: &'static [u16],
+        // only `None` for `Name::EMPTY`.
+        code_point: PackedCodePoint,
+        codepoint_repr: InlineStr<6>,
+    },
+    Generated {
+        kind: GeneratedKind,
+        code_point: PackedCodePoint,
+        text: SmallString,
+    },
+}
+
+/// A compact [`NameKind`], to keep `Name` small.
+#[derive(Copy, Clone)]
+enum GeneratedKind {
+    Explicit,
+    HangulSyllable,
+    Nr2(Nr2Prefix),
+    Label(LabelKind),
+}
+
+impl GeneratedKind {
+    fn to_name_kind(self) -> NameKind {
+        match self {
+            GeneratedKind::Explicit => NameKind::Explicit,
+            GeneratedKind::HangulSyllable => NameKind::HangulSyllable,
+            GeneratedKind::Nr2(prefix) => NameKind::Nr2 {
+                prefix: prefix.as_str(),
+            },
+            GeneratedKind::Label(kind) => NameKind::Label { kind },
+        }
+    }
+
+    fn to_name_derivation(self) -> NameDerivation {
+        match self {
+            GeneratedKind::Explicit => NameDerivation::Explicit,
+            GeneratedKind::HangulSyllable => NameDerivation::Nr1HangulSyllable,
+            GeneratedKind::Nr2(_) => NameDerivation::Nr2,
+            GeneratedKind::Label(kind) => NameDerivation::Label(kind),
+        }
+    }
+}
+
+/// An `Option<u32>` in four bytes rather than eight, to keep `Name` small.
+#[derive(Copy, Clone)]
+struct PackedCodePoint(Option<NonZeroU32>);
+
+impl PackedCodePoint {
+    const NONE: PackedCodePoint = PackedCodePoint(None);
+
+    fn some(v: u32) -> PackedCodePoint {
+        // code points never reach `u32::MAX`, so the complement isn't zero.
+        PackedCodePoint(NonZeroU32::new(!v))
+    }
+
+    fn get(self) -> Option<u32> {
+        self.0.map(|v| !v.get())
+    }
+}
+
+impl From<Option<u32>> for PackedCodePoint {
+    fn from(v: Option<u32>) -> PackedCodePoint {
+        v.map_or(PackedCodePoint::NONE, PackedCodePoint::some)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Nr2Prefix {
+    CjkUnifiedIdeograph,
+    TangutIdeograph,
+}
+
+impl Nr2Prefix {
+    const ALL: &'static [Nr2Prefix] = &[Nr2Prefix::CjkUnifiedIdeograph, Nr2Prefix::TangutIdeograph];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Nr2Prefix::CjkUnifiedIdeograph => "CJK UNIFIED IDEOGRAPH-",
+            Nr2Prefix::TangutIdeograph => "TANGUT IDEOGRAPH-",
+        }
+    }
+
+    /// The prefix as it appears in a loose matching key, where the hyphen
+    /// before the code point is medial.
+    fn loose_key(self) -> &'static str {
+        match self {
+            Nr2Prefix::CjkUnifiedIdeograph => "CJKUNIFIEDIDEOGRAPH",
+            Nr2Prefix::TangutIdeograph => "TANGUTIDEOGRAPH",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Name(NameInner);
+
+/// Describes how a [`Name`] was produced, see [`Name::kind`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NameKind {
+    /// A name listed explicitly in the Unicode Character Database.
+    Explicit,
+    /// A Hangul syllable name, derived by rule NR1.
+    HangulSyllable,
+    /// A name derived by rule NR2, like "CJK UNIFIED IDEOGRAPH-4E00".
+    Nr2 {
+        /// The prefix the code point is appended to.
+        prefix: &'static str,
+    },
+    /// A code point label, like `<control-0081>`.
+    Label {
+        /// The type of the labeled code point.
+        kind: LabelKind,
+    },
+}
+
+/// The type of code point a label like `<control-0081>` stands for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LabelKind {
+    Control,
+    Reserved,
+    Noncharacter,
+    PrivateUse,
+    Surrogate,
+}
+
+impl LabelKind {
+    /// Returns the prefix of labels of this kind, like "control-".
+    pub fn prefix(self) -> &'static str {
+        match self {
+            LabelKind::Control => "control-",
+            LabelKind::Reserved => "reserved-",
+            LabelKind::Noncharacter => "noncharacter-",
+            LabelKind::PrivateUse => "private-use-",
+            LabelKind::Surrogate => "surrogate-",
+        }
+    }
+}
+
+/// How [`code_point_label`] renders a label.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LabelStyle {
+    /// Encloses the label in angle brackets, like `<control-0009>`.
+    pub angle_brackets: bool,
+    /// Uppercases the prefix, like `CONTROL-0009`.
+    pub uppercase: bool,
+}
+
+impl LabelStyle {
+    /// The style of [`CharName::char_name`], like `<control-0009>`.
+    pub const BRACKETED: LabelStyle = LabelStyle {
+        angle_brackets: true,
+        uppercase: false,
+    };
+
+    /// The style of [`CharName::char_label`], like `control-0009`.
+    pub const BARE: LabelStyle = LabelStyle {
+        angle_brackets: false,
+        uppercase: false,
+    };
+}
+
+/// Choices for naming code points, see [`NameOptions::name_of`].
+///
+/// The default options name code points like [`CharName::char_name`].
+/// Names for people rather than programs can use corrections and control
+/// aliases, without brackets around labels:
+///
+/// ```
+/// use unicode_charname::{LabelStyle, NameOptions};
+///
+/// let options = NameOptions::new()
+///     .corrections(true)
+///     .control_aliases(true)
+///     .label_style(LabelStyle::BARE);
+/// let name_of = |cp| options.name_of(cp).unwrap().to_string();
+/// assert_eq!(name_of(0x0A), "LINE FEED");
+/// assert_eq!(name_of(0x41), "LATIN CAPITAL LETTER A");
+/// assert_eq!(
+///     name_of(0xFE18),
+///     "PRESENTATION FORM FOR VERTICAL RIGHT WHITE LENTICULAR BRACKET"
+/// );
+/// assert_eq!(name_of(0x0081), "control-0081");
+/// assert_eq!(name_of(0x0378), "reserved-0378");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NameOptions {
+    corrections: bool,
+    control_aliases: bool,
+    label_style: LabelStyle,
+    unassigned_labels: bool,
+}
+
+impl NameOptions {
+    /// The options of [`CharName::char_name`].
+    pub fn new() -> NameOptions {
+        NameOptions {
+            corrections: false,
+            control_aliases: false,
+            label_style: LabelStyle::BRACKETED,
+            unassigned_labels: true,
+        }
+    }
+
+    /// Uses correction aliases instead of the names they correct, see
+    /// [`CharName::corrected_name`]. Off by default.
+    pub fn corrections(mut self, corrections: bool) -> NameOptions {
+        self.corrections = corrections;
+        self
+    }
+
+    /// Uses control aliases instead of `<control-XXXX>` labels, see
+    /// [`CharName::control_alias`]. Off by default.
+    pub fn control_aliases(mut self, control_aliases: bool) -> NameOptions {
+        self.control_aliases = control_aliases;
+        self
+    }
+
+    /// Renders code point labels in `style`, [`LabelStyle::BRACKETED`] by
+    /// default.
+    pub fn label_style(mut self, style: LabelStyle) -> NameOptions {
+        self.label_style = style;
+        self
+    }
+
+    /// Gives labels to reserved code points and noncharacters, rather than
+    /// `None`. On by default.
+    pub fn unassigned_labels(mut self, unassigned_labels: bool) -> NameOptions {
+        self.unassigned_labels = unassigned_labels;
+        self
+    }
+
+    /// Returns the name of the code point `cp` with these options.
+    ///
+    /// Returns `None` for values above U+10FFFF.
+    pub fn name_of(&self, cp: u32) -> Option<Name> {
+        if self.control_aliases {
+            if let Some(alias) = cp.control_alias() {
+                return Some(alias_name(cp, alias));
+            }
+        }
+        if self.corrections {
+            if let Some(alias) = correction_alias(cp) {
+                return Some(alias_name(cp, alias));
+            }
+        }
+        if let Some(name) = cp.property_name() {
+            return Some(name);
+        }
+        if !self.unassigned_labels && !is_assigned(cp) {
+            return None;
+        }
+        code_point_label(cp, self.label_style)
+    }
+}
+
+impl Default for NameOptions {
+    fn default() -> NameOptions {
+        NameOptions::new()
+    }
+}
+
+/// The basic type of a code point, see [`CharName::code_point_type`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CodePointType {
+    /// Letters, marks, numbers, punctuation, symbols and spaces.
+    Graphic,
+    /// Invisible characters affecting neighboring ones, and line and
+    /// paragraph separators.
+    Format,
+    Control,
+    PrivateUse,
+    /// A surrogate code point, without telling high and low surrogates
+    /// apart, see [`surrogate_kind`].
+    Surrogate,
+    Noncharacter,
+    Reserved,
+}
+
+/// The kind of a surrogate code point, see [`surrogate_kind`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SurrogateKind {
+    /// U+D800 to U+DB7F, leading surrogates for planes 1 to 14.
+    High,
+    /// U+DB80 to U+DBFF, leading surrogates for the private-use planes 15
+    /// and 16.
+    PrivateUseHigh,
+    /// U+DC00 to U+DFFF, trailing surrogates.
+    Low,
+}
+
+/// The type of a formal name alias, see [`CharName::name_aliases`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AliasKind {
+    /// Corrects a seriously defective name.
+    Correction,
+    /// An ISO 6429 name of a control function.
+    Control,
+    /// A widely used alternate name.
+    Alternate,
+    /// A name documented but never actually in a standard.
+    Figment,
+    /// A common abbreviation, like "ZWJ".
+    Abbreviation,
+}
+
+impl AliasKind {
+    /// All the kinds of aliases, in the order of UAX #44.
+    pub const ALL: &'static [AliasKind] = &[
+        AliasKind::Correction,
+        AliasKind::Control,
+        AliasKind::Alternate,
+        AliasKind::Figment,
+        AliasKind::Abbreviation,
+    ];
+}
+
+/// A version of the Unicode Standard, see [`CharName::age`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnicodeVersion {
+    pub major: u8,
+    pub minor: u8,
+    pub micro: u8,
+}
+
+impl fmt::Display for UnicodeVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.micro)
+    }
+}
+
+/// A range of code points from Blocks.txt, see [`CharName::block`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Block {
+    first: u32,
+    last: u32,
+    name: &'static str,
+}
+
+impl Block {
+    /// Returns the block named `name` in Blocks.txt, ignoring case,
+    /// spaces, underscores and hyphens, so "Latin-1 Supplement" is also
+    /// found as "latin_1_supplement" or "LATIN 1 SUPPLEMENT".
+    ///
+    /// ```
+    /// use unicode_charname::Block;
+    ///
+    /// let block = Block::from_name("arrows").unwrap();
+    /// assert_eq!(block.range(), 0x2190..=0x21FF);
+    /// assert_eq!(Block::from_name("Arrowz"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Block> {
+        tables::BLOCKS
+            .iter()
+            .find(|&&(_, _, block_name)| loose_block_name(block_name).eq(loose_block_name(name)))
+            .map(|&(first, last, name)| Block { first, last, name })
+    }
+
+    /// Returns the name of the block, like "Basic Latin".
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the code points of the block, assigned or not.
+    pub fn range(&self) -> RangeInclusive<u32> {
+        self.first..=self.last
+    }
+}
+
+/// The rule deriving the name of a code point, see
+/// [`CharName::name_derivation`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NameDerivation {
+    /// The name is listed in UnicodeData.txt.
+    Explicit,
+    /// The name of a Hangul syllable, built from its jamo (rule NR1).
+    Nr1HangulSyllable,
+    /// The name is a prefix followed by the code point in hex (rule NR2).
+    Nr2,
+    /// The code point has no name, only a label.
+    Label(LabelKind),
+}
+
+impl Name {
+    /// The empty name, which no character has.
+    ///
+    /// It stands for "no name", like a missing name replaced by
+    /// `unwrap_or_default()`, and renders as an empty string.
+    pub const EMPTY: Name = Name(NameInner::Enumeration {
+        encoded_slice: &[],
+        code_point: PackedCodePoint::NONE,
+        codepoint_repr: InlineStr::new(),
+    });
+
+    /// Creates a name that belongs to no character, like the name of
+    /// a private-use character.
+    ///
+    /// The name must follow the syntax of character names: uppercase
+    /// letters, digits, spaces and hyphens, starting with a letter, without
+    /// doubled or trailing separators and without a digit after a space.
+    /// Code point labels like `<control-0081>` aren't names and are rejected.
+    pub fn custom(s: impl Into<String>) -> Result<Name, InvalidNameSyntax> {
+        let s = s.into();
+        syntax::check_name(&s).map_err(|offset| InvalidNameSyntax { offset })?;
+        Ok(Name(NameInner::Generated {
+            kind: GeneratedKind::Explicit,
+            code_point: PackedCodePoint::NONE,
+            text: s.into(),
+        }))
+    }
+
+    /// Returns the length in bytes of the rendered name, without allocating.
+    pub fn len(&self) -> usize {
+        self.iter().map(str::len).sum()
+    }
+
+    /// Returns whether this is the [`Name::EMPTY`] name. The names of
+    /// characters are never empty.
+    pub fn is_empty(&self) -> bool {
+        self.iter().all(str::is_empty)
+    }
+
+    /// Returns the code point the name belongs to, or `None` for
+    /// [`Name::EMPTY`] and custom names.
+    pub fn code_point(&self) -> Option<u32> {
+        match &self.0 {
+            NameInner::Enumeration { code_point, .. } | NameInner::Generated { code_point, .. } => {
+                code_point.get()
+            }
+        }
+    }
+
+    /// Returns the character the name belongs to, or `None` for surrogate
+    /// code points, [`Name::EMPTY`] and custom names.
+    pub fn to_char(&self) -> Option<char> {
+        self.code_point().and_then(std::char::from_u32)
+    }
+
+    /// Returns how the name was produced.
+    pub fn kind(&self) -> NameKind {
+        match &self.0 {
+            NameInner::Enumeration { .. } => NameKind::Explicit,
+            NameInner::Generated { kind, .. } => kind.to_name_kind(),
+        }
+    }
+
+    /// Returns the prefix and the code point that make up a name derived
+    /// by rule NR2 or a code point label, like `("CJK UNIFIED IDEOGRAPH-",
+    /// 0x4E00)` or `("control-", 0x81)`.
+    ///
+    /// Returns `None` for Hangul syllables and for names listed in the name
+    /// tables, including ones like "CJK COMPATIBILITY IDEOGRAPH-F900".
+    pub fn algorithmic_parts(&self) -> Option<(&'static str, u32)> {
+        match &self.0 {
+            NameInner::Generated {
+                kind: GeneratedKind::Nr2(prefix),
+                code_point,
+                ..
+            } => Some((prefix.as_str(), code_point.get()?)),
+            NameInner::Generated {
+                kind: GeneratedKind::Label(kind),
+                code_point,
+                ..
+            } => Some((kind.prefix(), code_point.get()?)),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the words of the name.
+    ///
+    /// Both spaces and hyphens separate words, and the angle brackets
+    /// around code point labels are not part of any word. So
+    /// "HANGUL JUNGSEONG O-E" yields "HANGUL", "JUNGSEONG", "O" and "E",
+    /// and `<control-0081>` yields "control" and "0081".
+    pub fn words(&self) -> Words<'_> {
+        let inner = match &self.0 {
+            NameInner::Enumeration {
+                encoded_slice,
+                codepoint_repr,
+                ..
+            } => WordsInner::Enumeration {
+                encoded_slice,
+                codepoint_repr,
+            },
+            NameInner::Generated { text, .. } => WordsInner::Generated(text.as_str()),
+        };
+        Words { inner }
+    }
+
+    /// Returns the number of words of the name, as yielded by
+    /// [`Name::words`], without allocating.
+    ///
+    /// Hyphenated words count separately, so "HANGUL JUNGSEONG O-E" has
+    /// four words.
+    pub fn word_count(&self) -> usize {
+        self.words().len()
+    }
+
+    /// Returns the `n`th word of the name, counting from zero, as yielded
+    /// by [`Name::words`].
+    ///
+    /// Hyphenated words count separately and the code point of names like
+    /// "CJK UNIFIED IDEOGRAPH-4E00" is a word of its own, so the words of
+    /// that name are "CJK", "UNIFIED", "IDEOGRAPH" and "4E00".
+    pub fn nth_word(&self, n: usize) -> Option<&str> {
+        self.words().nth(n)
+    }
+
+    /// Returns the last word of the name, see [`Name::nth_word`].
+    pub fn last_word(&self) -> Option<&str> {
+        self.words().next_back()
+    }
+
+    /// Returns the name as a `'static` string if the whole name is
+    /// a single word from the name tables (like "OX").
+    pub fn as_static_str(&self) -> Option<&'static str> {
+        match &self.0 {
+            NameInner::Enumeration {
+                encoded_slice: &[word_idx],
+                ..
+            } if word_idx != tables::WORD_TABLE_INDEX_CODEPOINT => {
+                Some(tables::ENUMERATION_WORD_TABLE[word_idx as usize])
+            }
+            NameInner::Generated {
+                text: SmallString::Static(text),
+                ..
+            } => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Returns the rendered name, borrowing it from the static name tables
+    /// when possible, see [`Name::as_static_str`].
+    pub fn to_cow(&self) -> Cow<'static, str> {
+        match self.as_static_str() {
+            Some(s) => Cow::Borrowed(s),
+            None => Cow::Owned(self.to_string()),
+        }
+    }
+
+    /// Converts the name into a `String` with a single allocation, or none
+    /// when the name already keeps its text in a `String`.
+    pub fn into_string(self) -> String {
+        match self.0 {
+            NameInner::Generated {
+                text: SmallString::Heap(text),
+                ..
+            } => text,
+            _ => {
+                let mut s = String::with_capacity(self.len());
+                // writing into a `String` never fails.
+                let _ = self.write_to(&mut s);
+                s
+            }
+        }
+    }
+
+    /// Returns whether `word` is one of the words of the name,
+    /// as produced by [`Name::words`].
+    ///
+    /// Only whole words match, so "ARROW" is not found in
+    /// "LEFTWARDS ARROWHEAD", and "O-E" is never found at all since hyphens
+    /// separate words, while "E" is found in "HANGUL JUNGSEONG O-E".
+    pub fn contains_word(&self, word: &str) -> bool {
+        match &self.0 {
+            NameInner::Enumeration {
+                encoded_slice,
+                codepoint_repr,
+                ..
+            } => {
+                if word == codepoint_repr.as_str() {
+                    return encoded_slice.contains(&tables::WORD_TABLE_INDEX_CODEPOINT);
+                }
+                match find_word_index(word) {
+                    Some(word_idx) => encoded_slice.contains(&word_idx),
+                    None => false,
+                }
+            }
+            NameInner::Generated { .. } => self.words().any(|w| w == word),
+        }
+    }
+
+    /// Returns whether the rendered name starts with `prefix`.
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.bytes().take(prefix.len()).eq(prefix.bytes())
+    }
+
+    /// Returns whether the rendered name ends with `suffix`.
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        let len = self.len();
+        if suffix.len() > len {
+            return false;
+        }
+        self.bytes().skip(len - suffix.len()).eq(suffix.bytes())
+    }
+
+    /// Returns whether the rendered name equals `other`, ignoring ASCII
+    /// case, without allocating.
+    ///
+    /// Spaces and hyphens must match exactly, as with `==`.
+    pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        // the lengths match, so splitting never goes out of bounds.
+        let mut rest = other.as_bytes();
+        for s in self.iter() {
+            let (head, tail) = rest.split_at(s.len());
+            if !head.eq_ignore_ascii_case(s.as_bytes()) {
+                return false;
+            }
+            rest = tail;
+        }
+        true
+    }
+
+    /// Returns whether `candidate` matches the name under the loose
+    /// matching rule UAX44-LM2.
+    ///
+    /// Case, whitespace, underscores and medial hyphens are ignored, so
+    /// "zero width joiner" and "ZERO-WIDTH_JOINER" both match
+    /// "ZERO WIDTH JOINER". Hyphens next to a space are significant, as is
+    /// the hyphen of "HANGUL JUNGSEONG O-E", which would otherwise match
+    /// "HANGUL JUNGSEONG OE".
+    pub fn matches_loose(&self, candidate: &str) -> bool {
+        let name = LooseChars::new(self.chars(), is_loose_jungseong_o_e(self.chars()));
+        let candidate =
+            LooseChars::new(candidate.chars(), is_loose_jungseong_o_e(candidate.chars()));
+        name.eq(candidate)
+    }
+
+    /// Returns the byte offset of the first occurrence of `needle` in the
+    /// rendered name, without allocating.
+    pub fn find(&self, needle: &str) -> Option<usize> {
+        let len = self.len();
+        if needle.len() > len {
+            return None;
+        }
+        // names are short, so trying every offset is cheap enough.
+        (0..=len - needle.len()).find(|&offset| {
+            self.bytes()
+                .skip(offset)
+                .take(needle.len())
+                .eq(needle.bytes())
+        })
+    }
+
+    /// Returns whether `needle` occurs in the rendered name, see
+    /// [`Name::find`].
+    pub fn contains(&self, needle: &str) -> bool {
+        self.find(needle).is_some()
+    }
+
+    /// Returns a `Display` adapter rendering the rest of the name after
+    /// `prefix` and the space following it, or `None` if the name doesn't
+    /// start with `prefix`.
+    ///
+    /// So with the prefix "GREEK SMALL LETTER", "GREEK SMALL LETTER ALPHA"
+    /// renders as "ALPHA". A prefix ending in the middle of a word leaves
+    /// the rest of that word.
+    pub fn strip_prefix(&self, prefix: &str) -> Option<impl fmt::Display + '_> {
+        struct StripPrefix<'a> {
+            name: &'a Name,
+            skip: usize,
+        }
+        impl fmt::Display for StripPrefix<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut skip = self.skip;
+                for s in self.name.iter() {
+                    if skip >= s.len() {
+                        skip -= s.len();
+                        continue;
+                    }
+                    f.write_str(&s[skip..])?;
+                    skip = 0;
+                }
+                Ok(())
+            }
+        }
+        if !self.starts_with(prefix) {
+            return None;
+        }
+        let mut skip = prefix.len();
+        if self.bytes().nth(skip) == Some(b' ') {
+            skip += 1;
+        }
+        Some(StripPrefix { name: self, skip })
+    }
+
+    /// Returns a `Display` adapter rendering the name in lowercase,
+    /// like "latin small letter e with acute".
+    ///
+    /// Every letter is lowercased, including the hexadecimal digits
+    /// of code points in names like "cjk unified ideograph-4e00".
+    pub fn display_lowercase(&self) -> impl fmt::Display + '_ {
+        struct Lowercase<'a>(&'a Name);
+        impl fmt::Display for Lowercase<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                for c in self.0.chars() {
+                    f.write_char(c.to_ascii_lowercase())?;
+                }
+                Ok(())
+            }
+        }
+        Lowercase(self)
+    }
+
+    /// Returns a `Display` adapter rendering the name in title case,
+    /// like "Latin Capital Letter A".
+    ///
+    /// Words are separated by spaces and hyphens, so "HANGUL JUNGSEONG O-E"
+    /// becomes "Hangul Jungseong O-E". Words made of hexadecimal digits that
+    /// contain at least one decimal digit are kept in uppercase, so the code
+    /// point in "Cjk Unified Ideograph-4E00" stays readable.
+    pub fn display_titlecase(&self) -> impl fmt::Display + '_ {
+        struct Titlecase<'a>(&'a Name);
+        impl fmt::Display for Titlecase<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                // words never span across fragments, so each fragment
+                // can be handled on its own.
+                for s in self.0.iter() {
+                    let mut rest = s;
+                    while !rest.is_empty() {
+                        let word_end = rest.find(is_word_separator_char).unwrap_or(rest.len());
+                        let (word, tail) = rest.split_at(word_end);
+                        write_titlecase_word(f, word)?;
+                        let separator_end = tail
+                            .find(|c| !is_word_separator_char(c))
+                            .unwrap_or(tail.len());
+                        let (separator, tail) = tail.split_at(separator_end);
+                        f.write_str(separator)?;
+                        rest = tail;
+                    }
+                }
+                Ok(())
+            }
+        }
+        Titlecase(self)
+    }
+
+    /// Converts the name into a SCREAMING_SNAKE_CASE identifier,
+    /// like `LATIN_CAPITAL_LETTER_A`.
+    ///
+    /// Spaces and hyphens both become underscores, and the angle brackets
+    /// of code point labels are dropped, so `<control-0081>` becomes
+    /// `CONTROL_0081`. No two character names map to the same identifier.
+    /// An identifier that would start with a digit is prefixed with
+    /// an underscore.
+    pub fn to_identifier(&self) -> String {
+        let mut identifier = String::with_capacity(self.len() + 1);
+        for c in self.chars() {
+            match c {
+                ' ' | '-' => identifier.push('_'),
+                '<' | '>' => {}
+                _ => {
+                    if identifier.is_empty() && c.is_ascii_digit() {
+                        identifier.push('_');
+                    }
+                    identifier.push(c.to_ascii_uppercase());
+                }
+            }
+        }
+        identifier
+    }
+
+    /// Converts the name into a lowercase kebab-case slug suitable for
+    /// URLs and file names, like `latin-small-letter-e-with-acute`.
+    ///
+    /// Spaces become hyphens, existing hyphens are kept, and the angle
+    /// brackets of code point labels are dropped, so `<control-0081>`
+    /// becomes `control-0081`. The result only contains `[a-z0-9-]`.
+    pub fn to_slug(&self) -> String {
+        let mut slug = String::with_capacity(self.len());
+        for c in self.chars() {
+            match c {
+                ' ' | '-' => slug.push('-'),
+                '<' | '>' => {}
+                _ => slug.push(c.to_ascii_lowercase()),
+            }
+        }
+        slug
+    }
+
+    /// Converts the name into a PascalCase identifier, like
+    /// `LatinCapitalLetterA`.
+    ///
+    /// Each word is capitalized as in [`Name::display_titlecase`] and the
+    /// separators are removed, so hexadecimal code points stay uppercase
+    /// as in `CjkUnifiedIdeograph4E00`. An identifier that would start with
+    /// a digit is prefixed with an underscore.
+    ///
+    /// Since hyphens are dropped, the few names only distinguished by
+    /// a leading hyphen map to the same identifier: "TIBETAN LETTER A" and
+    /// "TIBETAN LETTER -A" both become `TibetanLetterA`, and likewise for
+    /// four similar letters in other scripts. Medial hyphens are kept apart
+    /// by the capitalization: "HANGUL JUNGSEONG O-E" becomes
+    /// `HangulJungseongOE`, "HANGUL JUNGSEONG OE" becomes `HangulJungseongOe`.
+    pub fn to_camel_case(&self) -> String {
+        let mut identifier = String::with_capacity(self.len() + 1);
+        for word in self.words() {
+            if identifier.is_empty() && word.starts_with(|c: char| c.is_ascii_digit()) {
+                identifier.push('_');
+            }
+            // writing into a `String` never fails.
+            let _ = write_titlecase_word(&mut identifier, word);
+        }
+        identifier
+    }
+
+    /// Returns a `Display` adapter rendering the name as a `\N{...}` escape,
+    /// like `\N{LATIN SMALL LETTER A}`.
+    ///
+    /// Code point labels aren't names, so those render as `\u{0081}` style
+    /// escapes of their code point instead.
+    pub fn escape(&self) -> Escape<'_> {
+        Escape { name: self }
+    }
+
+    /// Returns an iterator over the lines of the name wrapped to `width`
+    /// bytes.
+    ///
+    /// Lines only break at spaces, so hyphenated words and the code point
+    /// of names like "CJK UNIFIED IDEOGRAPH-4E00" are never split. Words
+    /// longer than `width` get a line of their own and overflow it.
+    pub fn wrapped(&self, width: usize) -> Wrapped<'_> {
+        Wrapped {
+            parts: self.parts(),
+            width,
+        }
+    }
+
+    /// Returns an iterator over the characters of the rendered name.
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.iter().flat_map(str::chars)
+    }
+
+    /// Returns an iterator over the bytes of the rendered name, which are
+    /// all ASCII, so the iterator yields [`Name::len`] bytes.
+    pub fn bytes(&self) -> impl Iterator<Item = u8> + '_ {
+        self.iter().flat_map(str::bytes)
+    }
+
+    /// Writes the rendered name into `w` piece by piece, without allocating.
+    ///
+    /// The output is identical to the `Display` output.
+    pub fn write_to<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        for s in self.iter() {
+            w.write_str(s)?;
+        }
+        Ok(())
+    }
+
+    /// Returns an iterator over the parts of the rendered name, telling
+    /// words, separators and code points apart.
+    ///
+    /// Concatenating the parts gives the `Display` output.
+    pub fn parts(&self) -> NameParts<'_> {
+        let hex_start = match &self.0 {
+            NameInner::Generated {
+                kind: GeneratedKind::Label(kind),
+                text,
+                ..
+            } => usize::from(text.starts_with('<')) + kind.prefix().len(),
+            NameInner::Generated {
+                kind: GeneratedKind::Nr2(_),
+                text,
+                ..
+            } => text
+                .trim_end_matches('>')
+                .rfind('-')
+                .map_or(text.len(), |idx| idx + 1),
+            NameInner::Generated { text, .. } => text.len(),
+            NameInner::Enumeration { .. } => 0,
+        };
+        NameParts {
+            name: &self.0,
+            offset: 0,
+            hex_start,
+            state: NameIterState::Initial,
+        }
+    }
+
+    fn iter(&self) -> NameIter<'_> {
+        NameIter {
+            parts: self.parts(),
+        }
+    }
+}
+
+/// Returns [`Name::EMPTY`].
+impl Default for Name {
+    fn default() -> Name {
+        Name::EMPTY
+    }
+}
+
+/// Iterates over the words of the name, like [`Name::words`].
+///
+/// ```
+/// use unicode_charname::NamedChar;
+///
+/// let name = '\u{1180}'.name();
+/// let mut words = Vec::new();
+/// for word in &name {
+///     words.push(word);
+/// }
+/// assert_eq!(words, ["HANGUL", "JUNGSEONG", "O", "E"]);
+/// ```
+impl<'a> IntoIterator for &'a Name {
+    type Item = &'a str;
+    type IntoIter = Words<'a>;
+
+    fn into_iter(self) -> Words<'a> {
+        self.words()
+    }
+}
+
+impl From<Name> for String {
+    fn from(name: Name) -> String {
+        name.into_string()
+    }
+}
+
+impl From<Name> for Cow<'static, str> {
+    fn from(name: Name) -> Cow<'static, str> {
+        match name.as_static_str() {
+            Some(s) => Cow::Borrowed(s),
+            None => Cow::Owned(name.into_string()),
+        }
+    }
+}
+
+/// The alternate flag prefixes the name with its code point, as in
+/// `U+0041 LATIN CAPITAL LETTER A`.
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // "U+" and up to 6 hexadecimal digits, followed by a space.
+        let mut prefix = InlineStr::<9>::new();
+        if let Some(code_point) = self.code_point().filter(|_| f.alternate()) {
+            write!(prefix, "{} ", format_codepoint(code_point))
+                .expect("code points fit in the buffer");
+        }
+        let prefix = prefix.as_str();
+        if f.width().is_none() && f.precision().is_none() {
+            f.write_str(prefix)?;
+            return self.write_to(f);
+        }
+        // names are ASCII, so byte lengths are also character counts.
+        let len = prefix.len() + self.len();
+        let shown = f.precision().map_or(len, |precision| precision.min(len));
+        let padding = f.width().map_or(0, |width| width.saturating_sub(shown));
+        let (pre_padding, post_padding) = match f.align() {
+            None | Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        };
+        let fill = f.fill();
+        for _ in 0..pre_padding {
+            f.write_char(fill)?;
+        }
+        let mut remaining = shown;
+        for s in std::iter::once(prefix).chain(self.iter()) {
+            if remaining == 0 {
+                break;
+            }
+            let s = &s[..s.len().min(remaining)];
+            f.write_str(s)?;
+            remaining -= s.len();
+        }
+        for _ in 0..post_padding {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // names are plain ASCII without quotes or backslashes,
+        // so quoting them is enough to get `str`-like debug output.
+        struct Quoted<'a>(&'a Name);
+        impl fmt::Debug for Quoted<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "\"{}\"", self.0)
+            }
+        }
+        f.debug_tuple("Name").field(&Quoted(self)).finish()
+    }
+}
+
+/// Names compare equal when their rendered text does, whichever way they
+/// are stored.
+impl PartialEq for Name {
+    fn eq(&self, other: &Name) -> bool {
+        if let (
+            NameInner::Enumeration {
+                encoded_slice,
+                codepoint_repr,
+                ..
+            },
+            NameInner::Enumeration {
+                encoded_slice: other_encoded_slice,
+                codepoint_repr: other_codepoint_repr,
+                ..
+            },
+        ) = (&self.0, &other.0)
+        {
+            // the encoding of a given text is unique.
+            return encoded_slice == other_encoded_slice
+                && (codepoint_repr.as_str() == other_codepoint_repr.as_str()
+                    || !encoded_slice.contains(&tables::WORD_TABLE_INDEX_CODEPOINT));
+        }
+        self.bytes().eq(other.bytes())
+    }
+}
 
-pub use tables::UNICODE_VERSION;
+impl Eq for Name {}
 
-pub trait CharName {
-    fn char_name(self) -> Option<Name>;
-    fn property_name(self) -> Option<Name>;
+impl PartialOrd for Name {
+    fn partial_cmp(&self, other: &Name) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
-impl CharName for char {
-    fn char_name(self) -> Option<Name> {
-        CharName::char_name(self as u32)
-    }
-    fn property_name(self) -> Option<Name> {
-        CharName::property_name(self as u32)
+impl Ord for Name {
+    fn cmp(&self, other: &Name) -> Ordering {
+        self.bytes().cmp(other.bytes())
     }
 }
 
-impl CharName for u32 {
-    fn char_name(self) -> Option<Name> {
-        if let Some(slice) = tables::find_in_enumerate_names(self) {
-            let name = Name(NameInner::Enumeration {
-                encoded_slice: slice,
-                codepoint_repr: format!("{:04X}", self),
-            });
-            return Some(name);
-        }
-        if let Some(special_group) = tables::find_in_special_groups(self) {
-            return name_for_special_group_char(
-                self,
-                special_group,
-                CodePointLabelMode::Label {
-                    use_angle_bracket: true,
-                },
-            );
+impl Hash for Name {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // hash the rendered text the same way `str` does, so that names
+        // with different internal representations hash equally.
+        for s in self.iter() {
+            state.write(s.as_bytes());
         }
-        if reserved::is_code_point(self) {
-            if reserved::is_noncharacter(self) {
-                return Some(code_point_label("noncharacter-", self, true));
-            } else {
-                return Some(code_point_label("reserved-", self, true));
+        state.write_u8(0xff);
+    }
+}
+
+impl PartialEq<str> for Name {
+    fn eq(&self, other: &str) -> bool {
+        let mut rest = other;
+        for s in self.iter() {
+            match rest.strip_prefix(s) {
+                Some(r) => rest = r,
+                None => return false,
             }
         }
-        None
+        rest.is_empty()
     }
+}
 
-    fn property_name(self) -> Option<Name> {
-        if let Some(slice) = tables::find_in_enumerate_names(self) {
-            let name = Name(NameInner::Enumeration {
-                encoded_slice: slice,
-                codepoint_repr: format!("{:04X}", self),
-            });
-            return Some(name);
-        }
-        if let Some(special_group) = tables::find_in_special_groups(self) {
-            return name_for_special_group_char(self, special_group, CodePointLabelMode::None);
-        }
-        None
+impl<'a> PartialEq<&'a str> for Name {
+    fn eq(&self, other: &&'a str) -> bool {
+        *self == **other
     }
 }
 
-fn nr1_name(_prefix: &str, v: u32) -> Name {
-    // ignore prefix here, because hangul_name will provide one.
-    let str = jamo::hangul_name(v);
-    Name(NameInner::Generated(str))
+/// A `\N{...}` escape of a [`Name`], see [`Name::escape`].
+#[derive(Clone)]
+pub struct Escape<'a> {
+    name: &'a Name,
 }
 
-fn nr2_name(prefix: &str, v: u32) -> Name {
-    Name(NameInner::Generated(format!("{}{:04X}", prefix, v)))
+impl fmt::Display for Escape<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.name.kind(), self.name.code_point()) {
+            (NameKind::Label { .. }, Some(code_point)) => write!(f, "\\u{{{:04X}}}", code_point),
+            _ => {
+                f.write_str("\\N{")?;
+                self.name.write_to(f)?;
+                f.write_str("}")
+            }
+        }
+    }
 }
 
-fn code_point_label(prefix: &str, v: u32, use_angle_bracket: bool) -> Name {
-    let str = if use_angle_bracket {
-        format!("<{}{:04X}>", prefix, v)
-    } else {
-        format!("{}{:04X}", prefix, v)
-    };
-    Name(NameInner::Generated(str))
+/// A code point like `U+0041`, see [`format_codepoint`].
+#[derive(Copy, Clone, Debug)]
+pub struct CodePointNotation {
+    code_point: u32,
 }
 
-enum CodePointLabelMode {
-    None,
-    Label { use_angle_bracket: bool },
+impl fmt::Display for CodePointNotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // "U+" and up to 8 hexadecimal digits for any `u32`.
+        let mut notation = InlineStr::<10>::new();
+        write!(notation, "U+{:04X}", self.code_point)?;
+        f.pad(&notation)
+    }
 }
 
-fn name_for_special_group_char(
-    v: u32,
-    special_group: tables::SpecialGroup,
-    code_point_label_mode: CodePointLabelMode,
-) -> Option<Name> {
-    use tables::SpecialGroup;
-    match special_group {
-        SpecialGroup::HangulSyllable => {
-            // NR1
-            Some(nr1_name("HANGUL SYLLABLE ", v))
-        }
-        SpecialGroup::CJKIdeographExtensionA
-        | SpecialGroup::CJKIdeograph
-        | SpecialGroup::CJKIdeographExtensionB
-        | SpecialGroup::CJKIdeographExtensionC
-        | SpecialGroup::CJKIdeographExtensionD
-        | SpecialGroup::CJKIdeographExtensionE
-        | SpecialGroup::CJKIdeographExtensionF
-        | SpecialGroup::CJKIdeographExtensionG => {
-            // NR2
-            Some(nr2_name("CJK UNIFIED IDEOGRAPH-", v))
-        }
-        SpecialGroup::TangutIdeograph | SpecialGroup::TangutIdeographSupplement => {
-            // NR2
-            Some(nr2_name("TANGUT IDEOGRAPH-", v))
+/// Displays a character for error messages, along with its code point
+/// and its name, like `'‑' (U+2011 NON-BREAKING HYPHEN)`.
+///
+/// Characters that don't show by themselves are escaped, like
+/// `'\u{200D}' (U+200D ZERO WIDTH JOINER)`: those of a [`CodePointType`]
+/// other than `Graphic`, and whitespace other than U+0020 SPACE. Quotes
+/// and backslashes are escaped with a backslash.
+///
+/// This format is stable: it only changes when a character's name or
+/// [`CodePointType`] changes in a new version of Unicode.
+///
+/// ```
+/// use unicode_charname::DisplayWithName;
+///
+/// assert_eq!(
+///     format!("unexpected character {}", DisplayWithName('\t')),
+///     r"unexpected character '\u{0009}' (U+0009 <control-0009>)"
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DisplayWithName(pub char);
+
+impl fmt::Display for DisplayWithName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = self.0;
+        f.write_char('\'')?;
+        match c {
+            '\'' | '\\' => write!(f, "\\{}", c)?,
+            _ => write_glyph(f, c)?,
         }
-        /* other NR2 cases already covered in UnicodeData.txt */
-        SpecialGroup::control => {
-            if let CodePointLabelMode::Label { use_angle_bracket } = code_point_label_mode {
-                Some(code_point_label("control-", v, use_angle_bracket))
-            } else {
-                None
-            }
+        write!(
+            f,
+            "' ({} {})",
+            format_codepoint(c as u32),
+            char_name_display(c as u32)
+        )
+    }
+}
+
+/// Writes `c` itself, or its `\u{XXXX}` escape when it isn't visible on
+/// its own.
+fn write_glyph(f: &mut fmt::Formatter<'_>, c: char) -> fmt::Result {
+    match c {
+        ' ' => f.write_char(c),
+        _ if c.is_whitespace() || c.code_point_type() != Some(CodePointType::Graphic) => {
+            write!(f, "\\u{{{:04X}}}", c as u32)
         }
-        SpecialGroup::NonPrivateUseHighSurrogate
-        | SpecialGroup::PrivateUseHighSurrogate
-        | SpecialGroup::LowSurrogate => {
-            if let CodePointLabelMode::Label { use_angle_bracket } = code_point_label_mode {
-                Some(code_point_label("surrogate-", v, use_angle_bracket))
-            } else {
-                None
-            }
+        _ => f.write_char(c),
+    }
+}
+
+/// A report on the characters of a string, see [`describe`].
+#[derive(Copy, Clone, Debug)]
+pub struct Describe<'a> {
+    s: &'a str,
+}
+
+impl fmt::Display for Describe<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.s.chars() {
+            write!(f, "{} ", format_codepoint(c as u32))?;
+            write_glyph(f, c)?;
+            writeln!(f, " {}", char_name_display(c as u32))?;
         }
-        SpecialGroup::PrivateUse
-        | SpecialGroup::Plane15PrivateUse
-        | SpecialGroup::Plane16PrivateUse => {
-            if let CodePointLabelMode::Label { use_angle_bracket } = code_point_label_mode {
-                Some(code_point_label("private-use-", v, use_angle_bracket))
-            } else {
-                None
+        Ok(())
+    }
+}
+
+/// The name of a code point, see [`char_name_display`].
+#[derive(Copy, Clone, Debug)]
+pub struct CharNameDisplay {
+    code_point: u32,
+}
+
+impl fmt::Display for CharNameDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match name(self.code_point) {
+            Some(name) => fmt::Display::fmt(&name, f),
+            None => {
+                let mut invalid = InlineStr::<18>::new();
+                write!(invalid, "<invalid-{:08X}>", self.code_point)?;
+                f.pad(&invalid)
             }
         }
     }
 }
 
-#[derive(Clone)]
-enum NameInner {
-    Enumeration {
-        encoded_slice: &'static [u16],
-        codepoint_repr: String,
-    },
-    Generated(String),
+/// A part of a rendered [`Name`], see [`Name::parts`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Part<'a> {
+    /// A word, like "LATIN" or the "control" of `<control-0081>`.
+    Word(&'a str),
+    /// The hexadecimal code point of a name like
+    /// "CJK UNIFIED IDEOGRAPH-4E00" or a label like `<control-0081>`.
+    CodePointHex(&'a str),
+    /// A space.
+    Space,
+    /// A hyphen.
+    Hyphen,
+    /// The `<` opening a code point label.
+    OpenBracket,
+    /// The `>` closing a code point label.
+    CloseBracket,
 }
 
-#[derive(Clone)]
-pub struct Name(NameInner);
-
-impl Name {
-    fn iter(&self) -> NameIter<'_> {
-        NameIter {
-            name: &self.0,
-            offset: 0,
-            state: NameIterState::Initial,
+impl<'a> Part<'a> {
+    /// Returns the text of the part.
+    pub fn as_str(&self) -> &'a str {
+        match *self {
+            Part::Word(s) | Part::CodePointHex(s) => s,
+            Part::Space => " ",
+            Part::Hyphen => "-",
+            Part::OpenBracket => "<",
+            Part::CloseBracket => ">",
         }
     }
 }
 
-impl fmt::Display for Name {
+impl fmt::Display for Part<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for s in self.iter() {
-            write!(f, "{}", s)?;
-        }
-        Ok(())
+        f.pad(self.as_str())
     }
 }
 
+/// An iterator over the parts of a [`Name`], see [`Name::parts`].
 #[derive(Clone)]
-#[non_exhaustive]
-pub struct NameIter<'a> {
+pub struct NameParts<'a> {
     name: &'a NameInner,
     offset: usize,
+    hex_start: usize,
     state: NameIterState,
 }
 
@@ -191,13 +2797,14 @@ enum NameIterState {
     Finished,
 }
 
-impl<'a> Iterator for NameIter<'a> {
-    type Item = &'a str;
-    fn next(&mut self) -> Option<&'a str> {
+impl<'a> Iterator for NameParts<'a> {
+    type Item = Part<'a>;
+    fn next(&mut self) -> Option<Part<'a>> {
         match self.name {
             NameInner::Enumeration {
                 encoded_slice,
                 codepoint_repr,
+                ..
             } => match self.state {
                 NameIterState::Finished => None,
                 _ if self.offset >= encoded_slice.len() => {
@@ -206,7 +2813,7 @@ impl<'a> Iterator for NameIter<'a> {
                 }
                 NameIterState::InsertSpace { cur_special } => {
                     self.state = NameIterState::Middle { cur_special };
-                    Some(tables::ENUMERATION_WORD_TABLE[tables::WORD_TABLE_INDEX_SPACE as usize])
+                    Some(Part::Space)
                 }
                 _ => {
                     /* NameIterState::Initial | NameIterState::Middle {..} */
@@ -231,21 +2838,455 @@ impl<'a> Iterator for NameIter<'a> {
                     } else {
                         self.state = NameIterState::Finished;
                     }
-                    if cur_word_idx == tables::WORD_TABLE_INDEX_CODEPOINT {
-                        Some(codepoint_repr)
-                    } else {
-                        Some(tables::ENUMERATION_WORD_TABLE[cur_word_idx as usize])
-                    }
+                    Some(match cur_word_idx {
+                        tables::WORD_TABLE_INDEX_CODEPOINT => Part::CodePointHex(codepoint_repr),
+                        tables::WORD_TABLE_INDEX_SPACE => Part::Space,
+                        tables::WORD_TABLE_INDEX_HYPHEN => Part::Hyphen,
+                        _ => Part::Word(tables::ENUMERATION_WORD_TABLE[cur_word_idx as usize]),
+                    })
                 }
             },
-            NameInner::Generated(s) => match self.state {
-                NameIterState::Initial => {
-                    self.state = NameIterState::Finished;
-                    Some(&s)
+            NameInner::Generated { text, .. } => {
+                let rest = &text[self.offset..];
+                let part = match rest.as_bytes().first()? {
+                    b' ' => Part::Space,
+                    b'-' => Part::Hyphen,
+                    b'<' => Part::OpenBracket,
+                    b'>' => Part::CloseBracket,
+                    _ => {
+                        let len = rest.find(is_word_separator_char).unwrap_or(rest.len());
+                        let word = &rest[..len];
+                        if self.offset == self.hex_start {
+                            Part::CodePointHex(word)
+                        } else {
+                            Part::Word(word)
+                        }
+                    }
+                };
+                self.offset += part.as_str().len();
+                Some(part)
+            }
+        }
+    }
+}
+
+/// An iterator over the lines of a wrapped [`Name`], see [`Name::wrapped`].
+#[derive(Clone)]
+pub struct Wrapped<'a> {
+    parts: NameParts<'a>,
+    width: usize,
+}
+
+/// A line of a wrapped [`Name`], see [`Name::wrapped`].
+#[derive(Clone)]
+pub struct WrappedLine<'a> {
+    parts: NameParts<'a>,
+    len: usize,
+}
+
+impl WrappedLine<'_> {
+    /// Returns the length of the line in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the line is empty. Lines hold at least one word, so
+    /// this is always `false`.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl fmt::Display for WrappedLine<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut remaining = self.len;
+        for part in self.parts.clone() {
+            if remaining == 0 {
+                break;
+            }
+            f.write_str(part.as_str())?;
+            remaining -= part.as_str().len();
+        }
+        Ok(())
+    }
+}
+
+/// Consumes the spaces and the following word of `parts`, returning their
+/// lengths, or `None` if only spaces are left.
+fn next_spaced_word(parts: &mut NameParts<'_>) -> Option<(usize, usize)> {
+    let mut spaces = 0;
+    let mut word = 0;
+    loop {
+        let mut probe = parts.clone();
+        match probe.next() {
+            Some(Part::Space) if word > 0 => break,
+            Some(Part::Space) => spaces += 1,
+            Some(part) => word += part.as_str().len(),
+            None => break,
+        }
+        *parts = probe;
+    }
+    if word > 0 {
+        Some((spaces, word))
+    } else {
+        None
+    }
+}
+
+impl<'a> Iterator for Wrapped<'a> {
+    type Item = WrappedLine<'a>;
+
+    fn next(&mut self) -> Option<WrappedLine<'a>> {
+        let mut start = self.parts.clone();
+        let (spaces, mut len) = next_spaced_word(&mut self.parts)?;
+        for _ in 0..spaces {
+            start.next();
+        }
+        loop {
+            let mut probe = self.parts.clone();
+            match next_spaced_word(&mut probe) {
+                Some((spaces, word)) if len + spaces + word <= self.width => {
+                    len += spaces + word;
+                    self.parts = probe;
                 }
-                NameIterState::Finished => None,
-                _ => unreachable!(),
-            },
+                _ => break,
+            }
+        }
+        Some(WrappedLine { parts: start, len })
+    }
+}
+
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct NameIter<'a> {
+    parts: NameParts<'a>,
+}
+
+impl<'a> Iterator for NameIter<'a> {
+    type Item = &'a str;
+    fn next(&mut self) -> Option<&'a str> {
+        let s = self.parts.next()?.as_str();
+        debug_assert!(s.is_ascii(), "non-ASCII name fragment {:?}", s);
+        Some(s)
+    }
+}
+
+/// An iterator over the words of a [`Name`], see [`Name::words`].
+#[derive(Clone)]
+pub struct Words<'a> {
+    inner: WordsInner<'a>,
+}
+
+#[derive(Clone)]
+enum WordsInner<'a> {
+    Enumeration {
+        encoded_slice: &'static [u16],
+        codepoint_repr: &'a str,
+    },
+    Generated(&'a str),
+}
+
+fn is_hex_number(word: &str) -> bool {
+    word.bytes().all(|b| b.is_ascii_hexdigit()) && word.bytes().any(|b| b.is_ascii_digit())
+}
+
+fn write_titlecase_word<W: fmt::Write>(w: &mut W, word: &str) -> fmt::Result {
+    if is_hex_number(word) {
+        return w.write_str(word);
+    }
+    for (idx, c) in word.chars().enumerate() {
+        if idx == 0 {
+            w.write_char(c.to_ascii_uppercase())?;
+        } else {
+            w.write_char(c.to_ascii_lowercase())?;
+        }
+    }
+    Ok(())
+}
+
+fn find_word_index(word: &str) -> Option<u16> {
+    if word.is_empty() || word.contains(is_word_separator_char) {
+        return None;
+    }
+    let word_idx = tables::ENUMERATION_WORD_TABLE.binary_search(&word).ok()? as u16;
+    if word_idx == tables::WORD_TABLE_INDEX_CODEPOINT {
+        return None;
+    }
+    Some(word_idx)
+}
+
+fn is_word_separator_index(v: u16) -> bool {
+    v == tables::WORD_TABLE_INDEX_SPACE || v == tables::WORD_TABLE_INDEX_HYPHEN
+}
+
+fn is_word_separator_char(c: char) -> bool {
+    matches!(c, ' ' | '-' | '<' | '>')
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = &'a str;
+    fn next(&mut self) -> Option<&'a str> {
+        match &mut self.inner {
+            WordsInner::Enumeration {
+                encoded_slice,
+                codepoint_repr,
+            } => {
+                while let Some((&word_idx, rest)) = encoded_slice.split_first() {
+                    *encoded_slice = rest;
+                    if is_word_separator_index(word_idx) {
+                        continue;
+                    }
+                    if word_idx == tables::WORD_TABLE_INDEX_CODEPOINT {
+                        return Some(codepoint_repr);
+                    }
+                    return Some(tables::ENUMERATION_WORD_TABLE[word_idx as usize]);
+                }
+                None
+            }
+            WordsInner::Generated(s) => {
+                let rest = s.trim_start_matches(is_word_separator_char);
+                if rest.is_empty() {
+                    *s = rest;
+                    return None;
+                }
+                let end = rest.find(is_word_separator_char).unwrap_or(rest.len());
+                let (word, rest) = rest.split_at(end);
+                *s = rest;
+                Some(word)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = match &self.inner {
+            WordsInner::Enumeration { encoded_slice, .. } => encoded_slice
+                .iter()
+                .filter(|&&word_idx| !is_word_separator_index(word_idx))
+                .count(),
+            WordsInner::Generated(s) => s
+                .split(is_word_separator_char)
+                .filter(|word| !word.is_empty())
+                .count(),
+        };
+        (len, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for Words<'a> {
+    fn next_back(&mut self) -> Option<&'a str> {
+        match &mut self.inner {
+            WordsInner::Enumeration {
+                encoded_slice,
+                codepoint_repr,
+            } => {
+                while let Some((&word_idx, rest)) = encoded_slice.split_last() {
+                    *encoded_slice = rest;
+                    if is_word_separator_index(word_idx) {
+                        continue;
+                    }
+                    if word_idx == tables::WORD_TABLE_INDEX_CODEPOINT {
+                        return Some(codepoint_repr);
+                    }
+                    return Some(tables::ENUMERATION_WORD_TABLE[word_idx as usize]);
+                }
+                None
+            }
+            WordsInner::Generated(s) => {
+                let rest = s.trim_end_matches(is_word_separator_char);
+                if rest.is_empty() {
+                    *s = rest;
+                    return None;
+                }
+                let start = rest.rfind(is_word_separator_char).map_or(0, |idx| idx + 1);
+                let (rest, word) = rest.split_at(start);
+                *s = rest;
+                Some(word)
+            }
+        }
+    }
+}
+
+impl ExactSizeIterator for Words<'_> {}
+
+/// Folds `c` for loose matching. Besides ASCII letters, only the Kelvin sign
+/// and the long s have simple case foldings that can match a name.
+fn loose_fold(c: char) -> char {
+    match c {
+        '\u{212A}' => 'k',
+        '\u{017F}' => 's',
+        c => c.to_ascii_lowercase(),
+    }
+}
+
+fn is_loose_ignorable(c: char) -> bool {
+    c.is_whitespace() || c == '_'
+}
+
+fn is_loose_jungseong_o_e<I: Iterator<Item = char>>(chars: I) -> bool {
+    chars
+        .filter(|&c| !is_loose_ignorable(c))
+        .map(loose_fold)
+        .eq("hanguljungseongo-e".chars())
+}
+
+/// The characters of a string that are significant under UAX44-LM2,
+/// case folded.
+struct LooseChars<I: Iterator<Item = char>> {
+    chars: std::iter::Peekable<I>,
+    prev: Option<char>,
+    keep_medial_hyphens: bool,
+}
+
+impl<I: Iterator<Item = char>> LooseChars<I> {
+    fn new(chars: I, keep_medial_hyphens: bool) -> Self {
+        LooseChars {
+            chars: chars.peekable(),
+            prev: None,
+            keep_medial_hyphens,
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for LooseChars<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            let c = self.chars.next()?;
+            let prev = self.prev.replace(c);
+            if is_loose_ignorable(c) {
+                continue;
+            }
+            if c == '-' && !self.keep_medial_hyphens {
+                let is_medial = matches!(prev, Some(c) if c.is_alphanumeric())
+                    && matches!(self.chars.peek(), Some(c) if c.is_alphanumeric());
+                if is_medial {
+                    continue;
+                }
+            }
+            return Some(loose_fold(c));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns whether `v` is named like the code points of the
+    /// UnicodeData.txt range labeled `label`.
+    fn is_named_by_group(v: u32, label: &str) -> bool {
+        let name = match v.char_name() {
+            Some(name) => name,
+            None => return false,
+        };
+        let expected = if label == "Hangul Syllable" {
+            return name.kind() == NameKind::HangulSyllable;
+        } else if label == "control" {
+            format!("<control-{:04X}>", v)
+        } else if label.starts_with("CJK Ideograph") {
+            format!("CJK UNIFIED IDEOGRAPH-{:04X}", v)
+        } else if label.starts_with("Tangut Ideograph") {
+            format!("TANGUT IDEOGRAPH-{:04X}", v)
+        } else if label.ends_with("Surrogate") {
+            format!("<surrogate-{:04X}>", v)
+        } else if label.ends_with("Private Use") {
+            format!("<private-use-{:04X}>", v)
+        } else {
+            panic!("unexpected group {}", label);
+        };
+        name == expected.as_str()
+    }
+
+    #[test]
+    fn test_special_group_boundaries() {
+        let groups = special_group_ranges().collect::<Vec<_>>();
+        // neighbors in another group, like adjacent surrogate ranges, may be
+        // named the same way.
+        let in_any_group = |v: u32| groups.iter().any(|(range, _)| range.contains(&v));
+        for (range, label) in groups.iter().cloned() {
+            let (first, last) = (*range.start(), *range.end());
+            assert!(first <= last, "{}", label);
+            assert!(is_named_by_group(first, label), "{} U+{:04X}", label, first);
+            assert!(is_named_by_group(last, label), "{} U+{:04X}", label, last);
+            for neighbor in first.checked_sub(1).into_iter().chain(Some(last + 1)) {
+                if !in_any_group(neighbor) {
+                    assert!(
+                        !is_named_by_group(neighbor, label),
+                        "{} U+{:04X}",
+                        label,
+                        neighbor
+                    );
+                }
+            }
+            for v in range {
+                assert!(is_named_by_group(v, label), "{} U+{:04X}", label, v);
+            }
+        }
+        // the groups are sorted and disjoint, as `find_in_special_groups`
+        // searches them by binary search.
+        for pair in groups.windows(2) {
+            let (prev, next) = (&pair[0].0, &pair[1].0);
+            assert!(prev.end() < next.start(), "{:?} {:?}", prev, next);
+        }
+    }
+
+    #[test]
+    fn test_control_ranges() {
+        let controls = special_group_ranges()
+            .filter(|(_, label)| *label == "control")
+            .map(|(range, _)| range)
+            .collect::<Vec<_>>();
+        assert_eq!(controls, [0x0..=0x1F, 0x7F..=0x9F]);
+    }
+
+    #[cfg(feature = "phf")]
+    #[test]
+    fn test_lookup_listed_name_phf() {
+        for &v in tables::NAMES_BY_NAME {
+            let name = v.property_name().unwrap().to_string();
+            let c = std::char::from_u32(v);
+            assert_eq!(lookup_listed_name_phf(&name), c, "{}", name);
+            assert_eq!(lookup_listed_name(&name), c, "{}", name);
+            // near misses hash elsewhere, or to a name they have to be told
+            // apart from.
+            let lowercase = name.to_lowercase();
+            for not_a_name in [&name[1..], &name[..name.len() - 1], &lowercase] {
+                assert_eq!(
+                    lookup_listed_name_phf(not_a_name),
+                    lookup_listed_name(not_a_name),
+                    "{}",
+                    not_a_name
+                );
+            }
         }
+        for name in [
+            "",
+            "ox",
+            "NOT A NAME",
+            "SNOWMAN ",
+            "ZWJ",
+            "HANGUL SYLLABLE GAG",
+        ] {
+            assert_eq!(
+                lookup_listed_name_phf(name),
+                lookup_listed_name(name),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_search_candidate_count() {
+        // the arrows block is visited on its own, not the whole table.
+        assert_eq!(search_candidate_count(0x2190..=0x21FF), 0x70);
+        assert!(search_candidate_count(0..=0x10FFFF) > 30000);
+        assert_eq!(search_candidate_count(0x2195..=0x2195), 1);
+        // unassigned code points aren't visited.
+        assert_eq!(search_candidate_count(0xE0080..=0xE00FF), 0);
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = 0x21FF..=0x2190;
+        assert_eq!(search_candidate_count(empty), 0);
     }
 }
@@ -0,0 +1,120 @@
+//! A prebuilt index for repeated lookups by name.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::{loose_key, tables, CharName, Name, NAME_MAX_LEN};
+
+/// An index of every name and alias, for applications looking up many
+/// names.
+///
+/// [`lookup`](crate::lookup) and [`lookup_loose`](crate::lookup_loose) need
+/// no setup, but probe several tables and rebuild the names they compare
+/// against on every call. `NameIndex::new` pays for that once, keeping every
+/// name, the names derived by rule included, and every alias in hash maps.
+///
+/// This costs memory: the index owns a copy of every name and alias as
+/// written, another of each as a loose matching key, and a list of the
+/// named characters sorted by name, for prefix search. Building it renders
+/// every name, so build it once; it is `Send + Sync`, so it can be shared:
+///
+/// ```
+/// use std::sync::OnceLock;
+/// use unicode_charname::NameIndex;
+///
+/// static INDEX: OnceLock<NameIndex> = OnceLock::new();
+///
+/// let index = INDEX.get_or_init(NameIndex::new);
+/// assert_eq!(index.exact("SNOWMAN"), Some('\u{2603}'));
+/// assert_eq!(index.loose("snow-man"), Some('\u{2603}'));
+/// assert_eq!(index.prefix("SNOWMAN").count(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct NameIndex {
+    exact: HashMap<Box<str>, char>,
+    // keyed by the uppercase UAX44-LM2 keys.
+    loose: HashMap<Box<str>, char>,
+    // the named characters, sorted by name.
+    by_name: Vec<char>,
+}
+
+impl NameIndex {
+    /// Builds the index.
+    pub fn new() -> NameIndex {
+        let mut exact = HashMap::new();
+        let mut loose = HashMap::new();
+        let mut by_name = Vec::new();
+        let mut buf = String::new();
+        let mut loose_buf = [0; NAME_MAX_LEN];
+        for c in (0..=0x10_FFFF).filter_map(std::char::from_u32) {
+            let name = match c.property_name() {
+                Some(name) => name,
+                None => continue,
+            };
+            buf.clear();
+            name.write_to(&mut buf)
+                .expect("writing to a `String` never fails");
+            let key = loose_key(&buf, &mut loose_buf).expect("names have loose keys");
+            loose.insert(key.into(), c);
+            exact.insert(buf.as_str().into(), c);
+            by_name.push(c);
+        }
+        // names take precedence over aliases, as in `lookup`.
+        for &(alias, _, v) in tables::NAME_ALIASES_BY_ALIAS {
+            let c = std::char::from_u32(v).expect("aliases are of characters");
+            exact.entry(alias.into()).or_insert(c);
+            if let Some(key) = loose_key(alias, &mut loose_buf) {
+                loose.entry(key.into()).or_insert(c);
+            }
+        }
+        by_name.sort_by_cached_key(|c| {
+            c.property_name()
+                .expect("only named characters")
+                .to_string()
+        });
+        NameIndex {
+            exact,
+            loose,
+            by_name,
+        }
+    }
+
+    /// Returns the character named `name`, like [`lookup`](crate::lookup).
+    pub fn exact(&self, name: &str) -> Option<char> {
+        self.exact.get(name).copied()
+    }
+
+    /// Returns the character named `name` under the loose matching rule
+    /// UAX44-LM2, like [`lookup_loose`](crate::lookup_loose).
+    pub fn loose(&self, name: &str) -> Option<char> {
+        let mut buf = [0; NAME_MAX_LEN];
+        let key = loose_key(name, &mut buf)?;
+        self.loose.get(key).copied()
+    }
+
+    /// Returns the characters whose names start with `prefix`, with their
+    /// names, sorted by name.
+    ///
+    /// Unlike [`names_with_prefix`](crate::names_with_prefix), the names
+    /// derived by rule, like "CJK UNIFIED IDEOGRAPH-4E00", are found too.
+    pub fn prefix<'a>(&'a self, prefix: &str) -> impl Iterator<Item = (char, Name)> + 'a {
+        let cmp = |c: char| {
+            let name = c.property_name().expect("only named characters");
+            name.bytes().take(prefix.len()).cmp(prefix.bytes())
+        };
+        let start = self.by_name.partition_point(|&c| cmp(c) == Ordering::Less);
+        let end = self
+            .by_name
+            .partition_point(|&c| cmp(c) != Ordering::Greater);
+        self.by_name[start..end].iter().map(|&c| {
+            let name = c.property_name().expect("only named characters");
+            (c, name)
+        })
+    }
+}
+
+impl Default for NameIndex {
+    fn default() -> NameIndex {
+        NameIndex::new()
+    }
+}
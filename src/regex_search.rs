@@ -0,0 +1,85 @@
+//! Regex search over the names, behind the `regex` feature.
+
+use regex::Regex;
+use regex_syntax::hir::literal::{ExtractKind, Extractor};
+use regex_syntax::hir::Look;
+
+use crate::{CharName, Name};
+
+/// Returns the characters whose names match `re`, with their names, in code
+/// point order.
+///
+/// The regex is matched against each name as written by
+/// [`CharName::property_name`], including the names derived by rule like
+/// "CJK UNIFIED IDEOGRAPH-4E00", but not code point labels. Every name is
+/// rendered into the same buffer, and when the pattern is anchored at the
+/// start and begins with literal text, names not starting with it are
+/// skipped without rendering them.
+///
+/// The literal prefixes are taken from `re.as_str()`, so options set with
+/// `regex::RegexBuilder` besides case insensitivity, like ignoring
+/// whitespace, should be given as inline flags instead.
+///
+/// ```
+/// use regex::Regex;
+///
+/// let re = Regex::new(r"^LATIN (SMALL|CAPITAL) LETTER [AEIOU]$").unwrap();
+/// let vowels: String = unicode_charname::names_matching(&re).map(|(c, _)| c).collect();
+/// assert_eq!(vowels, "AEIOUaeiou");
+/// ```
+pub fn names_matching(re: &Regex) -> impl Iterator<Item = (char, Name)> + '_ {
+    let prefixes = literal_prefixes(re.as_str());
+    let mut buf = String::new();
+    (0..=0x10_FFFFu32).filter_map(move |v| {
+        let name = v.property_name()?;
+        if let Some(prefixes) = &prefixes {
+            if !prefixes
+                .iter()
+                .any(|prefix| starts_with_ignore_case(&name, prefix))
+            {
+                return None;
+            }
+        }
+        buf.clear();
+        name.write_to(&mut buf)
+            .expect("writing to a `String` never fails");
+        if !re.is_match(&buf) {
+            return None;
+        }
+        let c = std::char::from_u32(v).expect("named code points are characters");
+        Some((c, name))
+    })
+}
+
+/// Returns the literals every match of `pattern` starts with, or `None` if
+/// there are none to check against.
+fn literal_prefixes(pattern: &str) -> Option<Vec<Vec<u8>>> {
+    let hir = regex_syntax::parse(pattern).ok()?;
+    // unanchored patterns can match anywhere in a name.
+    if !hir.properties().look_set_prefix().contains(Look::Start) {
+        return None;
+    }
+    let seq = Extractor::new().kind(ExtractKind::Prefix).extract(&hir);
+    let literals = seq.literals()?;
+    // names are ASCII, so other literals may only match through case folding.
+    if literals
+        .iter()
+        .any(|literal| literal.as_bytes().is_empty() || !literal.as_bytes().is_ascii())
+    {
+        return None;
+    }
+    Some(
+        literals
+            .iter()
+            .map(|literal| literal.as_bytes().to_vec())
+            .collect(),
+    )
+}
+
+fn starts_with_ignore_case(name: &Name, prefix: &[u8]) -> bool {
+    name.len() >= prefix.len()
+        && name
+            .bytes()
+            .zip(prefix)
+            .all(|(a, &b)| a.eq_ignore_ascii_case(&b))
+}
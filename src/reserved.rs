@@ -4,20 +4,35 @@ pub(crate) fn is_code_point(v: u32) -> bool {
     v <= CODE_POINT_MAX
 }
 
-pub(crate) fn is_noncharacter(v: u32) -> bool {
-    match v {
-        0xFDD0..=0xFDEF
-        | 0xFFFE..=0xFFFF
-        | 0x1FFFE..=0x1FFFF
-        | 0x2FFFE..=0x2FFFF
-        | 0x3FFFE..=0x3FFFF
-        | 0x4FFFE..=0x4FFFF
-        | 0x5FFFE..=0x5FFFF
-        | 0x6FFFE..=0x6FFFF
-        | 0x7FFFE..=0x7FFFF
-        | 0x8FFFE..=0x8FFFF
-        | 0x9FFFE..=0x9FFFF
-        | 0x10FFFE..=0x10FFFF => true,
-        _ => false,
+pub(crate) const PLANE_NAMES: [&str; 17] = [
+    "Basic Multilingual Plane",
+    "Supplementary Multilingual Plane",
+    "Supplementary Ideographic Plane",
+    "Tertiary Ideographic Plane",
+    "Unassigned plane 4",
+    "Unassigned plane 5",
+    "Unassigned plane 6",
+    "Unassigned plane 7",
+    "Unassigned plane 8",
+    "Unassigned plane 9",
+    "Unassigned plane 10",
+    "Unassigned plane 11",
+    "Unassigned plane 12",
+    "Unassigned plane 13",
+    "Supplementary Special-purpose Plane",
+    "Supplementary Private Use Area-A",
+    "Supplementary Private Use Area-B",
+];
+
+pub(crate) fn plane(v: u32) -> Option<u8> {
+    if is_code_point(v) {
+        Some((v >> 16) as u8)
+    } else {
+        None
     }
 }
+
+pub(crate) fn is_noncharacter(v: u32) -> bool {
+    // the last two code points of each of the 17 planes.
+    matches!(v, 0xFDD0..=0xFDEF) || (is_code_point(v) && v & 0xFFFE == 0xFFFE)
+}
@@ -0,0 +1,79 @@
+use std::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::{syntax, CharName, GeneratedKind, LabelKind, Name, NameInner, NameKind};
+
+impl Serialize for Name {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+struct NameVisitor;
+
+impl<'de> Visitor<'de> for NameVisitor {
+    type Value = Name;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a Unicode character name or code point label")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Name, E> {
+        self.visit_string(v.to_owned())
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Name, E> {
+        if v.is_empty() {
+            return Ok(Name::EMPTY);
+        }
+        if let Some((kind, code_point, block)) = syntax::parse_label(&v) {
+            let code_point = Some(code_point).filter(|&cp| is_label_of(cp, kind, block));
+            return Ok(Name(NameInner::Generated {
+                kind: GeneratedKind::Label(kind),
+                code_point: code_point.into(),
+                text: v.into(),
+            }));
+        }
+        if let Err(offset) = syntax::check_name(&v) {
+            return Err(E::custom(format_args!(
+                "invalid character name {:?}: unexpected byte at offset {}",
+                v, offset
+            )));
+        }
+        // names that belong to no character, like those of `Name::custom`,
+        // come back without a code point.
+        let (kind, code_point) = match crate::lookup_name(&v) {
+            Some(c) => (syntax::name_kind(&v), Some(u32::from(c))),
+            None => (GeneratedKind::Explicit, crate::lookup(&v).map(u32::from)),
+        };
+        Ok(Name(NameInner::Generated {
+            kind,
+            code_point: code_point.into(),
+            text: v.into(),
+        }))
+    }
+}
+
+/// Returns whether `v` is labeled with `kind`, and lies in the block named
+/// `block` if there is one.
+fn is_label_of(v: u32, kind: LabelKind, block: Option<&str>) -> bool {
+    let label = match v.char_label() {
+        Some(label) => label,
+        None => return false,
+    };
+    if label.kind() != (NameKind::Label { kind }) {
+        return false;
+    }
+    match block {
+        Some(block) => v.block().map(|b| b.name()) == Some(block),
+        None => true,
+    }
+}
+
+impl<'de> Deserialize<'de> for Name {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Name, D::Error> {
+        deserializer.deserialize_string(NameVisitor)
+    }
+}
@@ -0,0 +1,115 @@
+// Small string storage, so that most names don't allocate.
+
+use std::fmt;
+use std::ops::Deref;
+
+/// A string of at most `N` bytes, stored inline.
+#[derive(Clone)]
+pub(crate) struct InlineStr<const N: usize> {
+    len: u8,
+    buf: [u8; N],
+}
+
+impl<const N: usize> InlineStr<N> {
+    pub(crate) const fn new() -> Self {
+        InlineStr {
+            len: 0,
+            buf: [0; N],
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        // only whole `str`s are ever copied in.
+        std::str::from_utf8(&self.buf[..self.len as usize]).expect("valid UTF-8")
+    }
+}
+
+impl<const N: usize> Deref for InlineStr<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Fails without writing anything when `s` doesn't fit.
+impl<const N: usize> fmt::Write for InlineStr<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let start = self.len as usize;
+        let end = start + s.len();
+        if end > N {
+            return Err(fmt::Error);
+        }
+        self.buf[start..end].copy_from_slice(s.as_bytes());
+        self.len = end as u8;
+        Ok(())
+    }
+}
+
+/// Generated names fit inline, only long custom names are allocated.
+const SMALL_STRING_INLINE_LEN: usize = 30;
+
+/// A string stored inline when short, on the heap otherwise.
+#[derive(Clone)]
+pub(crate) enum SmallString {
+    Inline(InlineStr<SMALL_STRING_INLINE_LEN>),
+    Heap(String),
+    Static(&'static str),
+}
+
+impl SmallString {
+    pub(crate) const fn new() -> Self {
+        SmallString::Inline(InlineStr::new())
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        match self {
+            SmallString::Inline(s) => s.as_str(),
+            SmallString::Heap(s) => s,
+            SmallString::Static(s) => s,
+        }
+    }
+}
+
+impl Deref for SmallString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<String> for SmallString {
+    fn from(s: String) -> Self {
+        let mut inline = InlineStr::new();
+        match fmt::Write::write_str(&mut inline, &s) {
+            Ok(()) => SmallString::Inline(inline),
+            Err(_) => SmallString::Heap(s),
+        }
+    }
+}
+
+/// Moves to the heap once the inline buffer is full.
+impl fmt::Write for SmallString {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        match self {
+            SmallString::Inline(inline) => {
+                if inline.write_str(s).is_err() {
+                    let mut heap = String::with_capacity(inline.as_str().len() + s.len());
+                    heap.push_str(inline.as_str());
+                    heap.push_str(s);
+                    *self = SmallString::Heap(heap);
+                }
+                Ok(())
+            }
+            SmallString::Heap(heap) => heap.write_str(s),
+            SmallString::Static(prefix) => {
+                let mut heap = String::with_capacity(prefix.len() + s.len());
+                heap.push_str(prefix);
+                heap.push_str(s);
+                *self = SmallString::Heap(heap);
+                Ok(())
+            }
+        }
+    }
+}
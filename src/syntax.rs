@@ -0,0 +1,101 @@
+// Character name syntax, adapted from Unicode 13.0, 4.8.
+
+#[cfg(feature = "serde")]
+use crate::{GeneratedKind, LabelKind, Nr2Prefix};
+
+#[cfg(feature = "serde")]
+const LABEL_KINDS: &[LabelKind] = &[
+    LabelKind::Control,
+    LabelKind::Reserved,
+    LabelKind::Noncharacter,
+    LabelKind::PrivateUse,
+    LabelKind::Surrogate,
+];
+
+/// Checks `s` against the character name syntax, returning the byte offset
+/// of the first offending byte on failure.
+///
+/// Names only contain uppercase letters, digits, spaces and hyphens. They
+/// start with a letter, don't end with a separator, never contain doubled
+/// separators, and words after a space don't start with a digit.
+pub(crate) fn check_name(s: &str) -> Result<(), usize> {
+    let bytes = s.as_bytes();
+    if bytes.is_empty() {
+        return Err(0);
+    }
+    if !bytes[0].is_ascii_uppercase() {
+        return Err(0);
+    }
+    for (idx, pair) in bytes.windows(2).enumerate() {
+        let (prev, b) = (pair[0], pair[1]);
+        let valid = match b {
+            b'A'..=b'Z' => true,
+            b'0'..=b'9' => prev != b' ',
+            b' ' | b'-' => prev != b && idx + 2 < bytes.len(),
+            _ => false,
+        };
+        if !valid {
+            return Err(idx + 1);
+        }
+    }
+    Ok(())
+}
+
+/// Splits a code point label into its kind, its code point and the block
+/// name that may follow it, in any style the crate renders labels in:
+/// `<control-0081>`, `control-0081`, `<CONTROL-0081>`, `CONTROL-0081` or
+/// `reserved-0378 (Greek and Coptic)`.
+#[cfg(feature = "serde")]
+pub(crate) fn parse_label(s: &str) -> Option<(LabelKind, u32, Option<&str>)> {
+    let (s, block) = match s.strip_suffix(')').and_then(|s| s.split_once(" (")) {
+        Some((label, block)) if !block.is_empty() && !block.contains(['(', ')']) => {
+            (label, Some(block))
+        }
+        _ => (s, None),
+    };
+    let s = match s.strip_prefix('<') {
+        Some(inner) if block.is_none() => inner.strip_suffix('>')?,
+        Some(_) => return None,
+        None => s,
+    };
+    let (kind, hex) = LABEL_KINDS.iter().find_map(|&kind| {
+        let prefix = kind.prefix();
+        let head = s.get(..prefix.len())?;
+        let uppercase = prefix.bytes().map(|b| b.to_ascii_uppercase());
+        if head == prefix || head.bytes().eq(uppercase) {
+            Some((kind, &s[prefix.len()..]))
+        } else {
+            None
+        }
+    })?;
+    if !is_code_point_hex(hex) || (block.is_some() && kind != LabelKind::Reserved) {
+        return None;
+    }
+    Some((kind, parse_hex(hex)?, block))
+}
+
+/// Guesses how a name with valid syntax was produced from its text alone.
+#[cfg(feature = "serde")]
+pub(crate) fn name_kind(s: &str) -> GeneratedKind {
+    if s.starts_with("HANGUL SYLLABLE ") {
+        return GeneratedKind::HangulSyllable;
+    }
+    for &prefix in Nr2Prefix::ALL {
+        if let Some(hex) = s.strip_prefix(prefix.as_str()) {
+            if is_code_point_hex(hex) {
+                return GeneratedKind::Nr2(prefix);
+            }
+        }
+    }
+    GeneratedKind::Explicit
+}
+
+#[cfg(feature = "serde")]
+fn parse_hex(s: &str) -> Option<u32> {
+    u32::from_str_radix(s, 16).ok()
+}
+
+#[cfg(feature = "serde")]
+fn is_code_point_hex(s: &str) -> bool {
+    (4..=6).contains(&s.len()) && s.bytes().all(|b| matches!(b, b'0'..=b'9' | b'A'..=b'F'))
+}
@@ -0,0 +1,97 @@
+//! Resolving `\N{...}` escapes in strings.
+
+use crate::error::{UnescapeError, UnescapeErrorKind};
+use crate::{lookup_any, lookup_any_loose, LookupResult};
+
+/// Replaces each `\N{name}` escape in `s` with the character called
+/// `name`, matching exactly like [`lookup_any`].
+///
+/// Names, aliases and the names of named sequences are all allowed, so
+/// `\N{KEYCAP DIGIT ONE}` becomes "1\u{FE0F}\u{20E3}". A `\\` becomes a
+/// single backslash, which is how to write a literal `\N{`; other
+/// backslashes are kept as they are.
+///
+/// Fails on the first escape that has no `{`, no closing `}`, another
+/// `{` within the name, or a name that isn't found.
+///
+/// ```
+/// use unicode_charname::unescape_named;
+///
+/// let s = unescape_named(r"snow: \N{SNOWMAN}\N{VARIATION SELECTOR-16}").unwrap();
+/// assert_eq!(s, "snow: \u{2603}\u{FE0F}");
+/// assert_eq!(unescape_named(r"\\N{SNOWMAN}").unwrap(), r"\N{SNOWMAN}");
+///
+/// let err = unescape_named(r"snow: \N{SNOWMEN}").unwrap_err();
+/// assert_eq!(err.span(), 6..17);
+/// assert_eq!(err.name(), "SNOWMEN");
+/// ```
+pub fn unescape_named(s: &str) -> Result<String, UnescapeError> {
+    unescape_with(s, lookup_any)
+}
+
+/// Replaces each `\N{name}` escape in `s` like [`unescape_named`], but
+/// matching names under the loose matching rule UAX44-LM2 like
+/// [`lookup_any_loose`].
+///
+/// ```
+/// use unicode_charname::unescape_named_loose;
+///
+/// let s = unescape_named_loose(r"\N{snowman}\N{zwj}\N{keycap digit one}").unwrap();
+/// assert_eq!(s, "\u{2603}\u{200D}1\u{FE0F}\u{20E3}");
+/// ```
+pub fn unescape_named_loose(s: &str) -> Result<String, UnescapeError> {
+    unescape_with(s, lookup_any_loose)
+}
+
+fn unescape_with(
+    s: &str,
+    lookup: impl Fn(&str) -> Option<LookupResult>,
+) -> Result<String, UnescapeError> {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut pos = 0;
+    while let Some(offset) = s[pos..].find('\\') {
+        let start = pos + offset;
+        unescaped.push_str(&s[pos..start]);
+        let error = |kind, end, name: &str| UnescapeError {
+            kind,
+            span: start..end,
+            name: name.to_string(),
+        };
+        match s.as_bytes().get(start + 1) {
+            Some(b'\\') => {
+                unescaped.push('\\');
+                pos = start + 2;
+            }
+            Some(b'N') => {
+                if s.as_bytes().get(start + 2) != Some(&b'{') {
+                    return Err(error(UnescapeErrorKind::MissingBrace, start + 2, ""));
+                }
+                let name_start = start + 3;
+                let name_end = match s[name_start..].find(['{', '}']) {
+                    Some(len) => name_start + len,
+                    None => {
+                        let name = &s[name_start..];
+                        return Err(error(UnescapeErrorKind::Unterminated, s.len(), name));
+                    }
+                };
+                let name = &s[name_start..name_end];
+                let end = name_end + 1;
+                if s.as_bytes()[name_end] == b'{' {
+                    return Err(error(UnescapeErrorKind::NestedBrace, end, name));
+                }
+                match lookup(name) {
+                    Some(LookupResult::Char(c)) => unescaped.push(c),
+                    Some(LookupResult::Sequence(sequence)) => unescaped.push_str(sequence),
+                    None => return Err(error(UnescapeErrorKind::UnknownName, end, name)),
+                }
+                pos = end;
+            }
+            _ => {
+                unescaped.push('\\');
+                pos = start + 1;
+            }
+        }
+    }
+    unescaped.push_str(&s[pos..]);
+    Ok(unescaped)
+}
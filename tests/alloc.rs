@@ -0,0 +1,163 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use unicode_charname::CharName;
+
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|c| c.set(c.get() + 1));
+        System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|c| c.set(c.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let before = ALLOCATIONS.with(|c| c.get());
+    let r = f();
+    let after = ALLOCATIONS.with(|c| c.get());
+    (r, after - before)
+}
+
+#[test]
+fn test_char_name_does_not_allocate() {
+    for &v in &[
+        0x41u32, 0x1180, 0xFBF9, 0xF900, 0xAC00, 0xD7A3, 0x4E00, 0x2A6DD, 0x17000, 0x0081, 0xD800,
+        0xE000, 0x10FFFD, 0xFDD0, 0x0378, 0x1029F,
+    ] {
+        let (name, count) = allocations(|| v.char_name().unwrap());
+        assert_eq!(count, 0, "U+{:04X}", v);
+        let (_, count) = allocations(|| v.property_name());
+        assert_eq!(count, 0, "U+{:04X}", v);
+        let (_, count) = allocations(|| v.name_derivation());
+        assert_eq!(count, 0, "U+{:04X}", v);
+        let (_, count) = allocations(|| v.has_explicit_name());
+        assert_eq!(count, 0, "U+{:04X}", v);
+        let (s, count) = allocations(|| name.into_string());
+        assert_eq!(count, 1, "U+{:04X}", v);
+        assert_eq!(s, v.char_name().unwrap().to_string());
+    }
+}
+
+#[test]
+fn test_char_name_display_does_not_allocate() {
+    use std::fmt::Write;
+
+    struct Sink(usize);
+
+    impl Write for Sink {
+        fn write_str(&mut self, s: &str) -> std::fmt::Result {
+            self.0 += s.len();
+            Ok(())
+        }
+    }
+
+    for &v in &[
+        0x41u32,
+        0xFBF9,
+        0xF900,
+        0xAC00,
+        0x4E00,
+        0x17000,
+        0x0009,
+        0xD800,
+        0xE000,
+        0xFDD0,
+        0x0378,
+        0x110000,
+        u32::MAX,
+    ] {
+        let mut sink = Sink(0);
+        let (_, count) = allocations(|| {
+            write!(sink, "{}", unicode_charname::char_name_display(v)).unwrap();
+            write!(sink, "{:>100}", v.char_name_display()).unwrap();
+        });
+        assert_eq!(count, 0, "U+{:04X}", v);
+        assert!(sink.0 > 100);
+    }
+
+    let s = "e\u{301}\t\u{AC00}\u{1F469}\u{200D}\u{1F4BB}".repeat(100);
+    let mut sink = Sink(0);
+    let (_, count) = allocations(|| write!(sink, "{}", unicode_charname::describe(&s)).unwrap());
+    assert_eq!(count, 0);
+    assert!(sink.0 > s.len());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_into_string_reuses_heap_text() {
+    let name: unicode_charname::Name = serde_json::from_str(
+        "\"ARABIC LIGATURE UIGHUR KIRGHIZ YEH WITH HAMZA ABOVE WITH ALEF MAKSURA ISOLATED FORM\"",
+    )
+    .unwrap();
+    let (s, count) = allocations(|| name.into_string());
+    assert_eq!(count, 0);
+    assert_eq!(s, 0xFBF9u32.char_name().unwrap().to_string());
+}
+
+#[test]
+fn test_name_into_reuses_buffer() {
+    let mut buf = String::with_capacity(unicode_charname::NAME_MAX_LEN);
+    let (_, count) = allocations(|| {
+        for v in (0..0x11_0000u32).step_by(7) {
+            assert!(v.name_into(&mut buf));
+            v.property_name_into(&mut buf);
+        }
+    });
+    assert_eq!(count, 0);
+    assert!(!0x110000u32.name_into(&mut buf));
+    assert!(buf.is_empty());
+    assert!(0xFBF9u32.name_into(&mut buf));
+    assert_eq!(buf, 0xFBF9u32.char_name().unwrap().to_string());
+    assert!(!0xE000u32.property_name_into(&mut buf));
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn test_matches_loose_does_not_allocate() {
+    let name = 0x1180u32.char_name().unwrap();
+    let (matched, count) = allocations(|| name.matches_loose("hangul jungseong o-e"));
+    assert!(matched);
+    assert_eq!(count, 0);
+}
+
+// the inline text has the same length on every target.
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn test_name_size() {
+    // names took 56 bytes before being stored inline.
+    assert_eq!(
+        std::mem::size_of::<unicode_charname::Name>(),
+        5 * std::mem::size_of::<usize>()
+    );
+}
+
+#[test]
+fn test_lookup_loose_and_ignore_case_do_not_allocate() {
+    for &name in &[
+        "zero width joiner",
+        "hangul syllable gag",
+        "cjk unified ideograph-4e00",
+    ] {
+        let (c, count) = allocations(|| unicode_charname::lookup_loose(name));
+        assert!(c.is_some(), "{}", name);
+        assert_eq!(count, 0, "{}", name);
+        let (c, count) = allocations(|| unicode_charname::lookup_ignore_case(name));
+        assert!(c.is_some(), "{}", name);
+        assert_eq!(count, 0, "{}", name);
+    }
+}
@@ -1,4 +1,7 @@
-use unicode_charname::CharName;
+use unicode_charname::{
+    AliasKind, CharName, CharNameError, CodePointType, LabelKind, LabelStyle, NameDerivation,
+    NameKind, Part, StrCharNames, UnicodeVersion,
+};
 
 fn some_s(s: &str) -> Option<String> {
     Some(s.to_string())
@@ -86,3 +89,3545 @@ fn test_names() {
         0xDC00u32.char_name().map(|x| x.to_string())
     );
 }
+
+#[test]
+fn test_control_names() {
+    for v in (0x0000..=0x001Fu32).chain(0x007F..=0x009F) {
+        assert!(v.property_name().is_none(), "U+{:04X}", v);
+        assert_eq!(
+            v.char_name().map(|x| x.to_string()),
+            Some(format!("<control-{:04X}>", v))
+        );
+    }
+    assert_eq!(
+        some_s("SPACE"),
+        0x0020u32.property_name().map(|x| x.to_string())
+    );
+    assert_eq!(
+        some_s("TILDE"),
+        0x007Eu32.property_name().map(|x| x.to_string())
+    );
+    assert_eq!(
+        some_s("NO-BREAK SPACE"),
+        0x00A0u32.property_name().map(|x| x.to_string())
+    );
+}
+
+#[test]
+fn test_char_label() {
+    assert_eq!(
+        some_s("control-0009"),
+        '\t'.char_label().map(|x| x.to_string())
+    );
+    assert_eq!(
+        some_s("private-use-E000"),
+        '\u{E000}'.char_label().map(|x| x.to_string())
+    );
+    assert_eq!(
+        some_s("surrogate-D800"),
+        0xD800u32.char_label().map(|x| x.to_string())
+    );
+    assert_eq!(
+        some_s("noncharacter-FDD0"),
+        0xFDD0u32.char_label().map(|x| x.to_string())
+    );
+    assert_eq!(
+        some_s("reserved-0378"),
+        0x0378u32.char_label().map(|x| x.to_string())
+    );
+    for &v in &[0x41u32, 0xFEFF, 0xAC00, 0x4E00, 0x17000, 0xF900] {
+        assert!(v.char_label().is_none(), "U+{:04X}", v);
+    }
+    assert!('A'.char_label().is_none());
+    assert!(0x110000u32.char_label().is_none());
+    let label = 0x0081u32.char_label().unwrap();
+    assert_eq!(
+        label.kind(),
+        NameKind::Label {
+            kind: LabelKind::Control
+        }
+    );
+    assert_eq!(
+        Some(format!("<{}>", label)),
+        0x0081u32.char_name().map(|x| x.to_string())
+    );
+}
+
+#[test]
+fn test_code_point_type() {
+    use CodePointType::*;
+    for &(v, expected) in &[
+        (0x0000u32, Some(Control)),
+        (0x001F, Some(Control)),
+        (0x0020, Some(Graphic)),
+        (0x007E, Some(Graphic)),
+        (0x007F, Some(Control)),
+        (0x009F, Some(Control)),
+        (0x00A0, Some(Graphic)),
+        (0x00AC, Some(Graphic)),
+        (0x00AD, Some(Format)),
+        (0x00AE, Some(Graphic)),
+        (0x200A, Some(Graphic)),
+        (0x200B, Some(Format)),
+        (0x200F, Some(Format)),
+        (0x2028, Some(Format)),
+        (0x2029, Some(Format)),
+        (0x202E, Some(Format)),
+        (0x202F, Some(Graphic)),
+        (0xFEFF, Some(Format)),
+        (0xE0001, Some(Format)),
+        (0xE007F, Some(Format)),
+        (0x0377, Some(Graphic)),
+        (0x0378, Some(Reserved)),
+        (0x0379, Some(Reserved)),
+        (0x037A, Some(Graphic)),
+        (0x4E00, Some(Graphic)),
+        (0xAC00, Some(Graphic)),
+        (0xD7A3, Some(Graphic)),
+        (0xD7A4, Some(Reserved)),
+        (0xD7FF, Some(Reserved)),
+        (0xD800, Some(Surrogate)),
+        (0xDB7F, Some(Surrogate)),
+        (0xDB80, Some(Surrogate)),
+        (0xDFFF, Some(Surrogate)),
+        (0xE000, Some(PrivateUse)),
+        (0xF8FF, Some(PrivateUse)),
+        (0xF900, Some(Graphic)),
+        (0xFDCF, Some(Reserved)),
+        (0xFDD0, Some(Noncharacter)),
+        (0xFDEF, Some(Noncharacter)),
+        (0xFDF0, Some(Graphic)),
+        (0xFFFD, Some(Graphic)),
+        (0xFFFE, Some(Noncharacter)),
+        (0xFFFF, Some(Noncharacter)),
+        (0x1FFFE, Some(Noncharacter)),
+        (0x17000, Some(Graphic)),
+        (0xEFFFF, Some(Noncharacter)),
+        (0xF0000, Some(PrivateUse)),
+        (0xFFFFD, Some(PrivateUse)),
+        (0xFFFFE, Some(Noncharacter)),
+        (0x100000, Some(PrivateUse)),
+        (0x10FFFD, Some(PrivateUse)),
+        (0x10FFFE, Some(Noncharacter)),
+        (0x10FFFF, Some(Noncharacter)),
+        (0x110000, None),
+        (u32::MAX, None),
+    ] {
+        assert_eq!(v.code_point_type(), expected, "U+{:04X}", v);
+    }
+    assert_eq!('\u{AD}'.code_point_type(), Some(Format));
+    assert_eq!('A'.code_point_type(), Some(Graphic));
+}
+
+#[test]
+fn test_name_derivation() {
+    use NameDerivation::*;
+    for &(v, expected) in &[
+        (0xAC00u32, Some(Nr1HangulSyllable)),
+        (0xD7A3, Some(Nr1HangulSyllable)),
+        (0x20000, Some(Nr2)),
+        (0x4E00, Some(Nr2)),
+        (0x17000, Some(Nr2)),
+        (0xF900, Some(Explicit)),
+        (0x0081, Some(Label(LabelKind::Control))),
+        (0xE000, Some(Label(LabelKind::PrivateUse))),
+        (0xD800, Some(Label(LabelKind::Surrogate))),
+        (0xFFFF, Some(Label(LabelKind::Noncharacter))),
+        (0x0378, Some(Label(LabelKind::Reserved))),
+        (0x41, Some(Explicit)),
+        (0x110000, None),
+    ] {
+        assert_eq!(v.name_derivation(), expected, "U+{:04X}", v);
+    }
+    assert_eq!('A'.name_derivation(), Some(Explicit));
+}
+
+#[test]
+fn test_has_explicit_name() {
+    for v in 0..=0x110010u32 {
+        assert_eq!(
+            v.has_explicit_name(),
+            v.property_name().is_some(),
+            "U+{:04X}",
+            v
+        );
+        assert_eq!(
+            v.has_name_or_label(),
+            v.char_name().is_some(),
+            "U+{:04X}",
+            v
+        );
+    }
+    assert!('A'.has_explicit_name());
+    assert!('\u{AC00}'.has_explicit_name());
+    assert!(!'\u{81}'.has_explicit_name());
+    assert!('\u{81}'.has_name_or_label());
+}
+
+#[test]
+fn test_try_char_name() {
+    use std::error::Error;
+    assert_eq!(
+        "LATIN CAPITAL LETTER A",
+        'A'.try_char_name().unwrap().to_string()
+    );
+    assert_eq!(
+        "<noncharacter-10FFFF>",
+        0x10FFFFu32.try_char_name().unwrap().to_string()
+    );
+    let err = 0x110000u32.try_char_name().unwrap_err();
+    assert_eq!(err, CharNameError::NotACodePoint { value: 0x110000 });
+    assert_eq!(err.to_string(), "0x110000 is not a Unicode code point");
+    assert!(err.source().is_none());
+    let err = u32::MAX.try_char_name().unwrap_err();
+    assert_eq!(err.to_string(), "0xFFFFFFFF is not a Unicode code point");
+    let err: Box<dyn Error> = Box::new(err);
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn test_char_name_display() {
+    use unicode_charname::char_name_display;
+    for &v in &[
+        0x41u32, 0xFBF9, 0xF900, 0xAC00, 0x4E00, 0x0009, 0xD800, 0xFDD0,
+    ] {
+        assert_eq!(
+            char_name_display(v).to_string(),
+            v.char_name().unwrap().to_string()
+        );
+    }
+    assert_eq!(
+        'A'.char_name_display().to_string(),
+        "LATIN CAPITAL LETTER A"
+    );
+    assert_eq!(
+        char_name_display(0x110000).to_string(),
+        "<invalid-00110000>"
+    );
+    assert_eq!(
+        format!("[{:>20}]", char_name_display(u32::MAX)),
+        "[  <invalid-FFFFFFFF>]"
+    );
+    assert_eq!(
+        format!("[{:<5}]", char_name_display(0x4F)),
+        "[LATIN CAPITAL LETTER O]"
+    );
+}
+
+#[test]
+fn test_name_aliases() {
+    use AliasKind::*;
+    assert_eq!(
+        '\0'.name_aliases(),
+        &[(Control, "NULL"), (Abbreviation, "NUL")]
+    );
+    assert_eq!(
+        '\n'.name_aliases(),
+        &[
+            (Control, "LINE FEED"),
+            (Control, "NEW LINE"),
+            (Control, "END OF LINE"),
+            (Abbreviation, "LF"),
+            (Abbreviation, "NL"),
+            (Abbreviation, "EOL"),
+        ]
+    );
+    assert_eq!(0x200Du32.name_aliases(), &[(Abbreviation, "ZWJ")]);
+    assert_eq!(
+        0xFE18u32.name_aliases(),
+        &[(
+            Correction,
+            "PRESENTATION FORM FOR VERTICAL RIGHT WHITE LENTICULAR BRACKET"
+        )]
+    );
+    assert_eq!(0xE01EFu32.name_aliases(), &[(Abbreviation, "VS256")]);
+    assert_eq!(0xE0100u32.name_aliases(), &[(Abbreviation, "VS17")]);
+    assert!(0xE01F0u32.name_aliases().is_empty());
+    assert!(0xE00FFu32.name_aliases().is_empty());
+    assert!('A'.name_aliases().is_empty());
+    assert!(0x110000u32.name_aliases().is_empty());
+    let kinds = |v: u32| {
+        v.name_aliases()
+            .iter()
+            .map(|&(kind, _)| kind)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(kinds(0x0080), [Figment, Abbreviation]);
+    assert_eq!(kinds(0xFEFF), [Alternate, Abbreviation, Abbreviation]);
+}
+
+#[test]
+fn test_corrected_name() {
+    assert_eq!(
+        some_s("PRESENTATION FORM FOR VERTICAL RIGHT WHITE LENTICULAR BRACKET"),
+        0xFE18u32.corrected_name().map(|x| x.to_string())
+    );
+    assert_eq!(
+        some_s("LATIN CAPITAL LETTER GHA"),
+        0x01A2u32.corrected_name().map(|x| x.to_string())
+    );
+    assert_eq!(
+        some_s("LAO LETTER FO FON"),
+        0x0E9Du32.corrected_name().map(|x| x.to_string())
+    );
+    let name = 0xFE18u32.corrected_name().unwrap();
+    assert_eq!(name.code_point(), Some(0xFE18));
+    assert_eq!(name.kind(), NameKind::Explicit);
+    assert_eq!(
+        name.as_static_str(),
+        Some("PRESENTATION FORM FOR VERTICAL RIGHT WHITE LENTICULAR BRACKET")
+    );
+    assert_eq!(name.last_word(), Some("BRACKET"));
+    for &v in &[0x41u32, 0x0009, 0xAC00, 0x4E00, 0x0378, 0x110000] {
+        assert_eq!(
+            v.corrected_name().map(|x| x.to_string()),
+            v.char_name().map(|x| x.to_string())
+        );
+    }
+    assert_eq!('A'.corrected_name().unwrap(), 'A'.char_name().unwrap());
+}
+
+#[test]
+fn test_unicode_1_0_name() {
+    assert_eq!(Some("BELL"), 0x0007u32.unicode_1_0_name());
+    assert_eq!(Some("NULL"), '\0'.unicode_1_0_name());
+    assert_eq!(Some("LINE FEED (LF)"), '\n'.unicode_1_0_name());
+    assert_eq!(Some("ESCAPE"), 0x001Bu32.unicode_1_0_name());
+    assert_eq!(Some("PERIOD"), '.'.unicode_1_0_name());
+    assert_eq!(Some("BROKEN VERTICAL BAR"), 0x00A6u32.unicode_1_0_name());
+    // MICRO SIGN kept its name.
+    assert_eq!(None, 0x00B5u32.unicode_1_0_name());
+    assert_eq!(None, 'A'.unicode_1_0_name());
+    assert_eq!(None, 0x1F514u32.unicode_1_0_name());
+    assert_eq!(None, 0x110000u32.unicode_1_0_name());
+}
+
+#[test]
+fn test_block() {
+    for &(v, expected) in &[
+        (0x0000u32, Some("Basic Latin")),
+        (0x007F, Some("Basic Latin")),
+        (0x0080, Some("Latin-1 Supplement")),
+        (0xAC00, Some("Hangul Syllables")),
+        (0xD7AF, Some("Hangul Syllables")),
+        (0xFB50, Some("Arabic Presentation Forms-A")),
+        (0xFDD0, Some("Arabic Presentation Forms-A")),
+        (0xFDFF, Some("Arabic Presentation Forms-A")),
+        (0xFE00, Some("Variation Selectors")),
+        (0x10FFFF, Some("Supplementary Private Use Area-B")),
+        // between Phags-pa and Saurashtra.
+        (0x0A880, Some("Saurashtra")),
+        (0x0A87F, Some("Phags-pa")),
+        // between Tangut Components and Tangut Supplement.
+        (0x18B00, Some("Khitan Small Script")),
+        (0x18D00, Some("Tangut Supplement")),
+        // unallocated gaps.
+        (0x2FE0, None),
+        (0x2FEF, None),
+        (0x1E030, None),
+        (0x30000, Some("CJK Unified Ideographs Extension G")),
+        (0x3134F, Some("CJK Unified Ideographs Extension G")),
+        (0x31350, None),
+        (0xDFFFF, None),
+        (0x110000, None),
+    ] {
+        assert_eq!(v.block_name(), expected, "U+{:04X}", v);
+    }
+    let block = 'A'.block().unwrap();
+    assert_eq!(block.name(), "Basic Latin");
+    assert_eq!(block.range(), 0x0000..=0x007F);
+    assert!(block.range().all(|v| v.block() == Some(block)));
+    let block = 0x1F600u32.block().unwrap();
+    assert_eq!(block.name(), "Emoticons");
+    assert_eq!(block.range(), 0x1F600..=0x1F64F);
+}
+
+#[test]
+fn test_plane() {
+    for &(v, plane, name) in &[
+        (0x0000u32, Some(0), Some("Basic Multilingual Plane")),
+        (0xFFFF, Some(0), Some("Basic Multilingual Plane")),
+        (0x10000, Some(1), Some("Supplementary Multilingual Plane")),
+        (0x20000, Some(2), Some("Supplementary Ideographic Plane")),
+        (0x3FFFF, Some(3), Some("Tertiary Ideographic Plane")),
+        (0x40000, Some(4), Some("Unassigned plane 4")),
+        (0xDFFFF, Some(13), Some("Unassigned plane 13")),
+        (
+            0xE0000,
+            Some(14),
+            Some("Supplementary Special-purpose Plane"),
+        ),
+        (0xF0000, Some(15), Some("Supplementary Private Use Area-A")),
+        (0x10FFFF, Some(16), Some("Supplementary Private Use Area-B")),
+        (0x110000, None, None),
+        (u32::MAX, None, None),
+    ] {
+        assert_eq!(v.plane(), plane, "U+{:04X}", v);
+        assert_eq!(v.plane_name(), name, "U+{:04X}", v);
+    }
+    assert_eq!('A'.plane(), Some(0));
+    assert_eq!(
+        '\u{1F402}'.plane_name(),
+        Some("Supplementary Multilingual Plane")
+    );
+}
+
+#[test]
+fn test_age() {
+    let version = |major, minor| {
+        Some(UnicodeVersion {
+            major,
+            minor,
+            micro: 0,
+        })
+    };
+    assert_eq!('A'.age(), version(1, 1));
+    assert_eq!(0x20BFu32.age(), version(10, 0));
+    assert_eq!(0x1F6D5u32.age(), version(12, 0));
+    assert_eq!(0x30000u32.age(), version(13, 0));
+    assert_eq!(0x3134Au32.age(), version(13, 0));
+    assert_eq!(0xAC00u32.age(), version(2, 0));
+    assert_eq!(0x0378u32.age(), None);
+    assert_eq!(0x3134Bu32.age(), None);
+    assert_eq!(0x110000u32.age(), None);
+    assert!('A'.age() < 0x20BFu32.age());
+    assert_eq!('A'.age().unwrap().to_string(), "1.1.0");
+}
+
+#[test]
+fn test_char_name_in() {
+    let version = |major, minor| UnicodeVersion {
+        major,
+        minor,
+        micro: 0,
+    };
+    let name_in =
+        |v: u32, major, minor| v.char_name_in(version(major, minor)).map(|x| x.to_string());
+    // added in 10.0, 11.0, 12.0, 12.1 and 13.0.
+    assert_eq!(some_s("BITCOIN SIGN"), name_in(0x20BF, 10, 0));
+    assert_eq!(some_s("<reserved-20BF>"), name_in(0x20BF, 9, 0));
+    assert_eq!(
+        some_s("ARMENIAN SMALL LETTER TURNED AYB"),
+        name_in(0x0560, 11, 0)
+    );
+    assert_eq!(some_s("<reserved-0560>"), name_in(0x0560, 10, 0));
+    assert_eq!(some_s("HINDU TEMPLE"), name_in(0x1F6D5, 12, 0));
+    assert_eq!(some_s("<reserved-1F6D5>"), name_in(0x1F6D5, 11, 0));
+    assert_eq!(some_s("SQUARE ERA NAME REIWA"), name_in(0x32FF, 12, 1));
+    assert_eq!(some_s("<reserved-32FF>"), name_in(0x32FF, 12, 0));
+    assert_eq!(
+        some_s("CJK UNIFIED IDEOGRAPH-30000"),
+        name_in(0x30000, 13, 0)
+    );
+    assert_eq!(some_s("<reserved-30000>"), name_in(0x30000, 12, 1));
+    // noncharacters keep their label.
+    assert_eq!(some_s("<noncharacter-FDD0>"), name_in(0xFDD0, 2, 0));
+    assert_eq!(some_s("<reserved-0378>"), name_in(0x0378, 13, 0));
+    assert_eq!(None, name_in(0x110000, 13, 0));
+    assert_eq!(
+        some_s("LATIN CAPITAL LETTER A"),
+        'A'.char_name_in(version(1, 1)).map(|x| x.to_string())
+    );
+    assert!(0x32FFu32.property_name_in(version(12, 0)).is_none());
+    assert_eq!(
+        some_s("SQUARE ERA NAME REIWA"),
+        0x32FFu32
+            .property_name_in(version(13, 0))
+            .map(|x| x.to_string())
+    );
+    assert!(0x0081u32.property_name_in(version(13, 0)).is_none());
+}
+
+#[test]
+fn test_jamo_short_names() {
+    use unicode_charname::jamo;
+    let check = |range: std::ops::RangeInclusive<u32>,
+                 short_name: fn(char) -> Option<&'static str>,
+                 from_short_name: fn(&str) -> Option<char>,
+                 count: usize| {
+        let jamos = range.filter_map(std::char::from_u32).collect::<Vec<_>>();
+        assert_eq!(jamos.len(), count);
+        for c in jamos {
+            let s = short_name(c).unwrap();
+            assert_eq!(from_short_name(s), Some(c), "{:?}", s);
+        }
+    };
+    check(
+        0x1100..=0x1112,
+        jamo::leading_consonant_short_name,
+        jamo::leading_consonant_from_short_name,
+        19,
+    );
+    check(
+        0x1161..=0x1175,
+        jamo::vowel_short_name,
+        jamo::vowel_from_short_name,
+        21,
+    );
+    // the 28th trailing consonant is the absence of one.
+    check(
+        0x11A8..=0x11C2,
+        jamo::trailing_consonant_short_name,
+        jamo::trailing_consonant_from_short_name,
+        27,
+    );
+    assert_eq!(jamo::leading_consonant_short_name('\u{1100}'), Some("G"));
+    assert_eq!(jamo::leading_consonant_short_name('\u{110B}'), Some(""));
+    assert_eq!(jamo::leading_consonant_short_name('\u{1113}'), None);
+    assert_eq!(jamo::vowel_short_name('\u{1175}'), Some("I"));
+    assert_eq!(jamo::vowel_short_name('\u{1160}'), None);
+    assert_eq!(jamo::trailing_consonant_short_name('\u{11A8}'), Some("G"));
+    assert_eq!(jamo::trailing_consonant_short_name('\u{11C2}'), Some("H"));
+    assert_eq!(jamo::trailing_consonant_short_name('\u{11A7}'), None);
+    assert_eq!(jamo::trailing_consonant_short_name('\u{11C3}'), None);
+    assert_eq!(
+        jamo::leading_consonant_from_short_name("GG"),
+        Some('\u{1101}')
+    );
+    assert_eq!(
+        jamo::trailing_consonant_from_short_name("GG"),
+        Some('\u{11A9}')
+    );
+    assert_eq!(jamo::trailing_consonant_from_short_name(""), None);
+    assert_eq!(jamo::vowel_from_short_name("G"), None);
+}
+
+#[test]
+fn test_hangul_name_parts() {
+    use unicode_charname::jamo::{hangul_jamos, hangul_name_parts};
+    assert_eq!(hangul_name_parts(0xAC10), Some(("G", "A", Some("M"))));
+    assert_eq!(
+        hangul_jamos(0xAC10),
+        Some(('\u{1100}', '\u{1161}', Some('\u{11B7}')))
+    );
+    assert_eq!(hangul_name_parts(0xAC00), Some(("G", "A", None)));
+    assert_eq!(hangul_jamos(0xAC00), Some(('\u{1100}', '\u{1161}', None)));
+    assert_eq!(hangul_name_parts(0xD7A3), Some(("H", "I", Some("H"))));
+    assert_eq!(
+        hangul_jamos(0xD7A3),
+        Some(('\u{1112}', '\u{1175}', Some('\u{11C2}')))
+    );
+    // U+C544 HANGUL SYLLABLE A starts with the silent IEUNG.
+    assert_eq!(hangul_name_parts(0xC544), Some(("", "A", None)));
+    for &v in &[0xABFFu32, 0xD7A4, 0x1100, 0x41, 0x110000] {
+        assert_eq!(hangul_name_parts(v), None);
+        assert_eq!(hangul_jamos(v), None);
+    }
+    for v in 0xAC00..=0xD7A3u32 {
+        let (l, v_, t) = hangul_name_parts(v).unwrap();
+        let name = format!("HANGUL SYLLABLE {}{}{}", l, v_, t.unwrap_or(""));
+        assert_eq!(v.char_name().unwrap(), *name);
+    }
+}
+
+#[test]
+fn test_code_point_label_style() {
+    use unicode_charname::code_point_label;
+    let styles = [
+        LabelStyle::BRACKETED,
+        LabelStyle::BARE,
+        LabelStyle {
+            angle_brackets: true,
+            uppercase: true,
+        },
+        LabelStyle {
+            angle_brackets: false,
+            uppercase: true,
+        },
+    ];
+    for &(v, expected) in &[
+        (
+            0x0009u32,
+            [
+                "<control-0009>",
+                "control-0009",
+                "<CONTROL-0009>",
+                "CONTROL-0009",
+            ],
+        ),
+        (
+            0xD800,
+            [
+                "<surrogate-D800>",
+                "surrogate-D800",
+                "<SURROGATE-D800>",
+                "SURROGATE-D800",
+            ],
+        ),
+        (
+            0x10FFFD,
+            [
+                "<private-use-10FFFD>",
+                "private-use-10FFFD",
+                "<PRIVATE-USE-10FFFD>",
+                "PRIVATE-USE-10FFFD",
+            ],
+        ),
+        (
+            0xFDD0,
+            [
+                "<noncharacter-FDD0>",
+                "noncharacter-FDD0",
+                "<NONCHARACTER-FDD0>",
+                "NONCHARACTER-FDD0",
+            ],
+        ),
+        (
+            0x0378,
+            [
+                "<reserved-0378>",
+                "reserved-0378",
+                "<RESERVED-0378>",
+                "RESERVED-0378",
+            ],
+        ),
+    ] {
+        for (&style, &expected) in styles.iter().zip(expected.iter()) {
+            let label = code_point_label(v, style).unwrap();
+            assert_eq!(label.to_string(), expected);
+            assert_eq!(label.len(), expected.len());
+            assert_eq!(label.code_point(), Some(v));
+        }
+        assert_eq!(code_point_label(v, LabelStyle::BRACKETED), v.char_name());
+        assert_eq!(code_point_label(v, LabelStyle::BARE), v.char_label());
+    }
+    for &v in &[0x41u32, 0xAC00, 0x4E00, 0x17000, 0x110000] {
+        for &style in &styles {
+            assert!(code_point_label(v, style).is_none(), "U+{:04X}", v);
+        }
+    }
+}
+
+#[test]
+fn test_format_codepoint() {
+    use unicode_charname::format_codepoint;
+    for &(v, expected) in &[
+        (0x0000u32, "U+0000"),
+        (0x0041, "U+0041"),
+        (0xFFFF, "U+FFFF"),
+        (0x10000, "U+10000"),
+        (0x1F402, "U+1F402"),
+        (0x10FFFF, "U+10FFFF"),
+    ] {
+        assert_eq!(format_codepoint(v).to_string(), expected);
+        assert_eq!(v.format_codepoint().to_string(), expected);
+    }
+    assert_eq!('a'.format_codepoint().to_string(), "U+0061");
+    assert_eq!(format!("[{:>8}]", format_codepoint(0x41)), "[  U+0041]");
+    assert_eq!(format_codepoint(u32::MAX).to_string(), "U+FFFFFFFF");
+}
+
+#[test]
+fn test_str_char_names() {
+    let s = "a\tb\u{301}\u{1F402}";
+    let names = s
+        .char_names()
+        .map(|(c, name)| (c, name.to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        [
+            ('a', "LATIN SMALL LETTER A".to_string()),
+            ('\t', "<control-0009>".to_string()),
+            ('b', "LATIN SMALL LETTER B".to_string()),
+            ('\u{301}', "COMBINING ACUTE ACCENT".to_string()),
+            ('\u{1F402}', "OX".to_string()),
+        ]
+    );
+    let offsets = s
+        .char_indices_names()
+        .map(|(offset, c, _)| (offset, c))
+        .collect::<Vec<_>>();
+    assert_eq!(offsets, s.char_indices().collect::<Vec<_>>());
+    let (offset, c, name) = s.char_indices_names().next_back().unwrap();
+    assert_eq!((offset, c), (5, '\u{1F402}'));
+    assert_eq!(name, "OX");
+    assert_eq!(s.char_names().rev().count(), 5);
+    assert!("".char_names().next().is_none());
+}
+
+#[test]
+fn test_display_with_name() {
+    use unicode_charname::DisplayWithName;
+    for &(c, expected) in &[
+        ('A', "'A' (U+0041 LATIN CAPITAL LETTER A)"),
+        ('\u{2011}', "'\u{2011}' (U+2011 NON-BREAKING HYPHEN)"),
+        ('\u{1F402}', "'\u{1F402}' (U+1F402 OX)"),
+        ('\u{301}', "'\u{301}' (U+0301 COMBINING ACUTE ACCENT)"),
+        (' ', "' ' (U+0020 SPACE)"),
+        ('\t', r"'\u{0009}' (U+0009 <control-0009>)"),
+        ('\u{85}', r"'\u{0085}' (U+0085 <control-0085>)"),
+        ('\u{A0}', r"'\u{00A0}' (U+00A0 NO-BREAK SPACE)"),
+        ('\u{2028}', r"'\u{2028}' (U+2028 LINE SEPARATOR)"),
+        ('\u{200D}', r"'\u{200D}' (U+200D ZERO WIDTH JOINER)"),
+        ('\u{FEFF}', r"'\u{FEFF}' (U+FEFF ZERO WIDTH NO-BREAK SPACE)"),
+        ('\u{E000}', r"'\u{E000}' (U+E000 <private-use-E000>)"),
+        ('\u{FFFF}', r"'\u{FFFF}' (U+FFFF <noncharacter-FFFF>)"),
+        ('\u{378}', r"'\u{0378}' (U+0378 <reserved-0378>)"),
+        ('\'', r"'\'' (U+0027 APOSTROPHE)"),
+        ('\\', r"'\\' (U+005C REVERSE SOLIDUS)"),
+    ] {
+        assert_eq!(DisplayWithName(c).to_string(), expected);
+    }
+}
+
+#[test]
+fn test_other_integer_types() {
+    let name = |name: Option<unicode_charname::Name>| name.map(|x| x.to_string());
+    assert_eq!(name(0x41u16.char_name()), some_s("LATIN CAPITAL LETTER A"));
+    assert_eq!(name(0u16.char_name()), some_s("<control-0000>"));
+    assert_eq!(name(0xD800u16.char_name()), some_s("<surrogate-D800>"));
+    assert_eq!(name(0xDFFFu16.char_name()), some_s("<surrogate-DFFF>"));
+    assert!(0xD800u16.property_name().is_none());
+    assert_eq!(name(u16::MAX.char_name()), some_s("<noncharacter-FFFF>"));
+    for &v in &[0x41u32, 0xD800, 0x1F402, 0x10FFFF] {
+        assert_eq!(name(u64::from(v).char_name()), name(v.char_name()));
+        assert_eq!(name((v as usize).char_name()), name(v.char_name()));
+        assert_eq!(u64::from(v).block(), v.block());
+    }
+    for &v in &[
+        0x110000u64,
+        u64::from(u32::MAX),
+        u64::from(u32::MAX) + 1,
+        u64::MAX,
+    ] {
+        assert!(v.char_name().is_none(), "{:#X}", v);
+        assert!(v.property_name().is_none(), "{:#X}", v);
+        assert!(v.plane().is_none(), "{:#X}", v);
+        assert!((v as usize).char_name().is_none(), "{:#X}", v);
+    }
+    // the low 32 bits of U+100000041 don't alias U+0041.
+    assert!(0x1_0000_0041u64.char_name().is_none());
+    assert!(usize::MAX.char_name().is_none());
+    assert!(u64::MAX.try_char_name().is_err());
+}
+
+#[test]
+fn test_control_alias() {
+    assert_eq!(Some("NULL"), '\0'.control_alias());
+    assert_eq!(Some("LINE FEED"), '\n'.control_alias());
+    assert_eq!(Some("NEXT LINE"), 0x0085u32.control_alias());
+    assert_eq!(
+        Some("APPLICATION PROGRAM COMMAND"),
+        0x009Fu32.control_alias()
+    );
+    assert_eq!(None, 0x0080u32.control_alias());
+    assert_eq!(None, 'A'.control_alias());
+    assert_eq!(None, 0x200Du32.control_alias());
+    assert_eq!(None, 0x110000u32.control_alias());
+    let with_alias = (0x0000..=0x001Fu32)
+        .chain(0x007F..=0x009F)
+        .filter(|v| v.control_alias().is_some())
+        .count();
+    assert_eq!(with_alias, 62);
+
+    let readable = |v: u32| v.readable_name().map(|x| x.to_string());
+    assert_eq!(some_s("NULL"), readable(0x0000));
+    assert_eq!(some_s("LINE FEED"), readable(0x000A));
+    assert_eq!(some_s("<control-0080>"), readable(0x0080));
+    assert_eq!(some_s("LATIN CAPITAL LETTER A"), readable(0x0041));
+    assert_eq!(some_s("<reserved-0378>"), readable(0x0378));
+    assert_eq!(None, readable(0x110000));
+    let name = '\n'.readable_name().unwrap();
+    assert_eq!(name.code_point(), Some(0x000A));
+    assert_eq!(name.words().collect::<Vec<_>>(), ["LINE", "FEED"]);
+}
+
+#[test]
+fn test_is_assigned() {
+    use unicode_charname::{is_assigned, is_code_point, is_noncharacter};
+    for v in 0xFDC0..=0xFDFFu32 {
+        let noncharacter = (0xFDD0..=0xFDEF).contains(&v);
+        assert_eq!(is_noncharacter(v), noncharacter, "U+{:04X}", v);
+        if noncharacter {
+            assert!(!is_assigned(v), "U+{:04X}", v);
+        }
+    }
+    assert!(is_assigned(0xFDF0));
+    // plane 3 only has CJK Unified Ideographs Extension G.
+    assert!(is_assigned(0x30000));
+    assert!(is_assigned(0x3134A));
+    assert!(!is_assigned(0x3134B));
+    assert!(!is_assigned(0x3FFFD));
+    assert!(is_noncharacter(0x3FFFE));
+    // plane 14 has the tags and the variation selectors.
+    assert!(!is_assigned(0xE0000));
+    assert!(is_assigned(0xE0001));
+    assert!(is_assigned(0xE01EF));
+    assert!(!is_assigned(0xE01F0));
+    assert!(is_noncharacter(0xEFFFF));
+    assert!(is_assigned(0x0009));
+    assert!(is_assigned(0xD800));
+    assert!(is_assigned(0xE000));
+    assert!(is_assigned(0x10FFFD));
+    assert!(!is_assigned(0x10FFFE));
+    assert!(is_code_point(0x10FFFF));
+    assert!(!is_code_point(0x110000));
+    assert!(!is_assigned(0x110000));
+    assert!(!is_noncharacter(0x11FFFE));
+    for v in (0..=0x10FFFFu32).step_by(7) {
+        let label = v.char_name().unwrap().to_string();
+        let unassigned = label.starts_with("<noncharacter-") || label.starts_with("<reserved-");
+        assert_eq!(is_assigned(v), !unassigned, "U+{:04X}", v);
+        assert_eq!(is_noncharacter(v), label.starts_with("<noncharacter-"));
+    }
+}
+
+#[test]
+fn test_named_sequences() {
+    use unicode_charname::{named_sequence, sequence_name};
+    assert_eq!(Some("KEYCAP DIGIT ONE"), sequence_name("1\u{FE0F}\u{20E3}"));
+    assert_eq!(
+        Some("1\u{FE0F}\u{20E3}"),
+        named_sequence("KEYCAP DIGIT ONE")
+    );
+    assert_eq!(Some("TAMIL CONSONANT K"), sequence_name("\u{B95}\u{BCD}"));
+    assert_eq!(Some("\u{B95}\u{BCD}"), named_sequence("TAMIL CONSONANT K"));
+    assert_eq!(
+        Some("TAMIL SYLLABLE KSSA"),
+        sequence_name("\u{B95}\u{BCD}\u{BB7}")
+    );
+    // prefixes and extensions of sequences don't match.
+    assert_eq!(None, sequence_name("1\u{FE0F}"));
+    assert_eq!(None, sequence_name("1\u{FE0F}\u{20E3}\u{20E3}"));
+    assert_eq!(None, named_sequence("KEYCAP DIGIT"));
+    assert_eq!(None, named_sequence("keycap digit one"));
+    assert_eq!(None, sequence_name("1"));
+    assert_eq!(None, sequence_name(""));
+    assert_eq!(None, named_sequence("LATIN CAPITAL LETTER A"));
+}
+
+#[test]
+fn test_describe() {
+    let s = "e\u{301}\t\u{1F469}\u{200D}\u{1F4BB}";
+    assert_eq!(
+        unicode_charname::describe(s).to_string(),
+        "U+0065 e LATIN SMALL LETTER E\n\
+         U+0301 \u{301} COMBINING ACUTE ACCENT\n\
+         U+0009 \\u{0009} <control-0009>\n\
+         U+1F469 \u{1F469} WOMAN\n\
+         U+200D \\u{200D} ZERO WIDTH JOINER\n\
+         U+1F4BB \u{1F4BB} PERSONAL COMPUTER\n"
+    );
+    assert_eq!(unicode_charname::describe("").to_string(), "");
+}
+
+#[test]
+fn test_annotate() {
+    use unicode_charname::annotate;
+    assert_eq!(
+        annotate('\u{E9}').to_string(),
+        "'\u{E9}' (U+00E9 LATIN SMALL LETTER E WITH ACUTE)"
+    );
+    assert_eq!(
+        annotate('\u{7}').to_string(),
+        r"'\u{0007}' (U+0007 <control-0007>)"
+    );
+    assert_eq!(
+        annotate('\u{200D}').to_string(),
+        r"'\u{200D}' (U+200D ZERO WIDTH JOINER)"
+    );
+    assert_eq!(
+        annotate('\u{F8FF}').to_string(),
+        r"'\u{F8FF}' (U+F8FF <private-use-F8FF>)"
+    );
+    assert_eq!(
+        'x'.annotate().map(|x| x.to_string()),
+        some_s("'x' (U+0078 LATIN SMALL LETTER X)")
+    );
+    assert_eq!(
+        0x10FFFDu32.annotate().map(|x| x.to_string()),
+        some_s(r"'\u{10FFFD}' (U+10FFFD <private-use-10FFFD>)")
+    );
+    assert!(0xD800u32.annotate().is_none());
+    assert!(0xD800u16.annotate().is_none());
+    assert!(0x110000u32.annotate().is_none());
+}
+
+#[cfg(feature = "emoji-names")]
+#[test]
+fn test_emoji_short_name() {
+    use unicode_charname::{char_emoji_short_name, emoji_short_name};
+    assert_eq!(unicode_charname::EMOJI_VERSION, (13, 0, 0));
+    assert_eq!(
+        emoji_short_name("\u{1F602}"),
+        Some("face with tears of joy")
+    );
+    assert_eq!(
+        emoji_short_name("\u{1F44D}\u{1F3FD}"),
+        Some("thumbs up: medium skin tone")
+    );
+    assert_eq!(emoji_short_name("\u{1F1EF}\u{1F1F5}"), Some("flag: Japan"));
+    assert_eq!(emoji_short_name("1\u{FE0F}\u{20E3}"), Some("keycap: 1"));
+    assert_eq!(
+        emoji_short_name("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F466}"),
+        Some("family: man, woman, boy")
+    );
+    assert_eq!(emoji_short_name("A"), None);
+    assert_eq!(emoji_short_name("hello"), None);
+    assert_eq!(emoji_short_name(""), None);
+    assert_eq!(emoji_short_name("\u{1F602}\u{1F602}"), None);
+    assert_eq!(emoji_short_name("\u{1F1EF}"), None);
+
+    assert_eq!(
+        char_emoji_short_name('\u{1F602}'),
+        Some("face with tears of joy")
+    );
+    assert_eq!(char_emoji_short_name('\u{263A}'), Some("smiling face"));
+    assert_eq!(char_emoji_short_name('A'), None);
+}
+
+#[test]
+fn test_named_char() {
+    use unicode_charname::NamedChar;
+    let mut kinds = Vec::new();
+    for plane in 0..=16u32 {
+        for &low in &[
+            0x0, 0x9, 0x41, 0x85, 0x378, 0x1180, 0x4E00, 0xAC00, 0xD7A3, 0xE000, 0xF900, 0xFDD0,
+            0xFFFD, 0xFFFE, 0xFFFF,
+        ] {
+            let c = match std::char::from_u32(plane << 16 | low) {
+                Some(c) => c,
+                None => continue,
+            };
+            assert_eq!(Some(c.name()), c.char_name(), "{:?}", c);
+            if let Some(NameDerivation::Label(kind)) = c.name_derivation() {
+                if !kinds.contains(&kind) {
+                    kinds.push(kind);
+                }
+            }
+        }
+    }
+    assert_eq!(kinds.len(), 4, "{:?}", kinds);
+    assert_eq!('\u{10FFFF}'.name(), "<noncharacter-10FFFF>");
+}
+
+#[test]
+fn test_abbreviation() {
+    let short = |v: u32| v.short_display().map(|x| x.to_string());
+    for &(v, abbreviation) in &[
+        (0x200Du32, "ZWJ"),
+        (0x200C, "ZWNJ"),
+        (0xA0, "NBSP"),
+        (0x200E, "LRM"),
+        (0xFE0F, "VS16"),
+        (0x61C, "ALM"),
+        (0xFEFF, "BOM"),
+        (0xA, "LF"),
+    ] {
+        assert_eq!(v.abbreviation(), Some(abbreviation));
+        assert_eq!(short(v), some_s(abbreviation));
+    }
+    assert_eq!('\u{200D}'.abbreviation(), Some("ZWJ"));
+    assert_eq!('A'.abbreviation(), None);
+    assert_eq!(short(0x41), some_s("LATIN CAPITAL LETTER A"));
+    assert_eq!(0x80u32.abbreviation(), Some("PAD"));
+    assert_eq!(short(0xE000), some_s("<private-use-E000>"));
+    assert_eq!(0x110000u32.abbreviation(), None);
+    assert_eq!(short(0x110000), None);
+}
+
+#[test]
+fn test_name_options() {
+    use unicode_charname::NameOptions;
+    let options = NameOptions::default();
+    for v in (0..0x11_0000u32).chain(vec![0x110000, u32::MAX]) {
+        assert_eq!(options.name_of(v), v.char_name(), "U+{:04X}", v);
+    }
+
+    let name_of = |options: NameOptions, v: u32| options.name_of(v).map(|x| x.to_string());
+    let options = NameOptions::new().unassigned_labels(false);
+    assert_eq!(name_of(options, 0x378), None);
+    assert_eq!(name_of(options, 0xFDD0), None);
+    assert_eq!(name_of(options, 0xE000), some_s("<private-use-E000>"));
+    assert_eq!(name_of(options, 0xD800), some_s("<surrogate-D800>"));
+    assert_eq!(name_of(options, 0x9), some_s("<control-0009>"));
+
+    let options = NameOptions::new().control_aliases(true);
+    assert_eq!(name_of(options, 0x9), some_s("CHARACTER TABULATION"));
+    assert_eq!(name_of(options, 0x80), some_s("<control-0080>"));
+    assert_eq!(
+        name_of(options, 0xFE18),
+        some_s("PRESENTATION FORM FOR VERTICAL RIGHT WHITE LENTICULAR BRAKCET")
+    );
+
+    let options = NameOptions::new()
+        .corrections(true)
+        .label_style(LabelStyle {
+            angle_brackets: false,
+            uppercase: true,
+        });
+    assert_eq!(name_of(options, 0x1A2), some_s("LATIN CAPITAL LETTER GHA"));
+    assert_eq!(name_of(options, 0x9), some_s("CONTROL-0009"));
+    assert_eq!(name_of(options, 0x110000), None);
+}
+
+#[test]
+fn test_char_label_with_block() {
+    let label = |v: u32| v.char_label_with_block().map(|x| x.to_string());
+    assert_eq!(label(0x061D), some_s("reserved-061D (Arabic)"));
+    assert_eq!(label(0x08B5), some_s("reserved-08B5 (Arabic Extended-A)"));
+    assert_eq!(label(0x2065), some_s("reserved-2065 (General Punctuation)"));
+    // plane 3 is only allocated up to the end of CJK Extension G.
+    assert_eq!(label(0x3FF00), some_s("reserved-3FF00"));
+    assert_eq!(label(0xE0080), some_s("reserved-E0080"));
+    assert_eq!(label(0xFDD0), some_s("noncharacter-FDD0"));
+    assert_eq!(label(0x3FFFE), some_s("noncharacter-3FFFE"));
+    assert_eq!(label(0x9), some_s("control-0009"));
+    assert_eq!(label(0xE000), some_s("private-use-E000"));
+    assert_eq!(label(0x41), None);
+    assert_eq!(label(0x110000), None);
+
+    let name = 0x08B5u32.char_label_with_block().unwrap();
+    assert_eq!(name.code_point(), Some(0x08B5));
+    assert_eq!(
+        name.kind(),
+        NameKind::Label {
+            kind: LabelKind::Reserved
+        }
+    );
+    assert_eq!(
+        name.parts().collect::<Vec<_>>(),
+        [
+            Part::Word("reserved"),
+            Part::Hyphen,
+            Part::CodePointHex("08B5"),
+            Part::Space,
+            Part::Word("(Arabic"),
+            Part::Space,
+            Part::Word("Extended"),
+            Part::Hyphen,
+            Part::Word("A)"),
+        ]
+    );
+}
+
+#[test]
+fn test_java_style_name() {
+    // as returned by `Character.getName`.
+    for &(v, expected) in &[
+        (0x41u32, "LATIN CAPITAL LETTER A"),
+        (0x0, "NULL"),
+        (0xA, "LINE FEED (LF)"),
+        (0x80, "LATIN 1 SUPPLEMENT 80"),
+        (0x3A1, "GREEK CAPITAL LETTER RHO"),
+        (0x20D0, "COMBINING LEFT HARPOON ABOVE"),
+        (0x4E00, "CJK UNIFIED IDEOGRAPHS 4E00"),
+        (0x20000, "CJK UNIFIED IDEOGRAPHS EXTENSION B 20000"),
+        (0xAC00, "HANGUL SYLLABLES AC00"),
+        (0xF900, "CJK COMPATIBILITY IDEOGRAPH-F900"),
+        (0xE000, "PRIVATE USE AREA E000"),
+        (0xF0000, "SUPPLEMENTARY PRIVATE USE AREA A F0000"),
+        (0xD800, "HIGH SURROGATES D800"),
+        (0xDC00, "LOW SURROGATES DC00"),
+    ] {
+        assert_eq!(v.java_style_name(), some_s(expected), "U+{:04X}", v);
+    }
+    assert_eq!(
+        '\u{4E00}'.java_style_name(),
+        some_s("CJK UNIFIED IDEOGRAPHS 4E00")
+    );
+    assert_eq!(0x378u32.java_style_name(), None);
+    assert_eq!(0x3A2u32.java_style_name(), None);
+    assert_eq!(0xFFFFu32.java_style_name(), None);
+    assert_eq!(0x110000u32.java_style_name(), None);
+}
+
+#[test]
+fn test_surrogate_kind() {
+    use unicode_charname::{surrogate_kind, SurrogateKind};
+    for &(v, kind) in &[
+        (0xD7FFu32, None),
+        (0xD800, Some(SurrogateKind::High)),
+        (0xD801, Some(SurrogateKind::High)),
+        (0xDB7F, Some(SurrogateKind::High)),
+        (0xDB80, Some(SurrogateKind::PrivateUseHigh)),
+        (0xDBFF, Some(SurrogateKind::PrivateUseHigh)),
+        (0xDC00, Some(SurrogateKind::Low)),
+        (0xDFFF, Some(SurrogateKind::Low)),
+        (0xE000, None),
+        (0x41, None),
+        (0x1D800, None),
+        (0x110000, None),
+    ] {
+        assert_eq!(surrogate_kind(v), kind, "U+{:04X}", v);
+        assert_eq!(
+            v.code_point_type() == Some(CodePointType::Surrogate),
+            kind.is_some()
+        );
+    }
+}
+
+#[test]
+fn test_code_point() {
+    use std::convert::TryFrom;
+    use unicode_charname::{CodePoint, ParseCodePointError};
+
+    let cp = |v: u32| CodePoint::try_from(v).unwrap();
+    assert_eq!(cp(0x41).value(), 0x41);
+    assert_eq!(cp(0x10FFFF), CodePoint::MAX);
+    assert_eq!(
+        CodePoint::try_from(0x110000),
+        Err(CharNameError::NotACodePoint { value: 0x110000 })
+    );
+    assert_eq!(CodePoint::from('A'), cp(0x41));
+    assert_eq!(u32::from(cp(0x41)), 0x41);
+    assert!(cp(0x41) < cp(0x42));
+
+    assert_eq!(cp(0x41).name(), "LATIN CAPITAL LETTER A");
+    assert_eq!(cp(0x41).label(), None);
+    assert_eq!(cp(0x41).to_char(), Some('A'));
+    // surrogates are code points, but not `char`s.
+    assert_eq!(cp(0xD800).name(), "<surrogate-D800>");
+    assert_eq!(cp(0xD800).property_name(), None);
+    assert_eq!(cp(0xD800).label().unwrap(), "surrogate-D800");
+    assert_eq!(cp(0xD800).to_char(), None);
+    assert_eq!(cp(0xD800).code_point_type(), Some(CodePointType::Surrogate));
+
+    assert_eq!(cp(0x41).to_string(), "U+0041");
+    assert_eq!(cp(0x1F600).to_string(), "U+1F600");
+    assert_eq!(
+        format!("{:>8}|{:<8}|", cp(0x41), cp(0x10FFFF)),
+        "  U+0041|U+10FFFF|"
+    );
+
+    for &s in &["U+0041", "u+0041", "0x41", "0X41", "41", "0041", "00000041"] {
+        assert_eq!(s.parse(), Ok(cp(0x41)), "{}", s);
+    }
+    assert_eq!("10FFFF".parse(), Ok(CodePoint::MAX));
+    assert_eq!("U+D800".parse(), Ok(cp(0xD800)));
+    for &s in &[
+        "",
+        "U+",
+        "0x",
+        "G",
+        "U+G",
+        "+41",
+        "-41",
+        " 41",
+        "41 ",
+        "U+U+41",
+        "0x0x41",
+        "4_1",
+        "000000041",
+    ] {
+        assert_eq!(
+            s.parse::<CodePoint>(),
+            Err(ParseCodePointError::Syntax),
+            "{:?}",
+            s
+        );
+    }
+    assert_eq!(
+        "U+110000".parse::<CodePoint>(),
+        Err(ParseCodePointError::NotACodePoint { value: 0x110000 })
+    );
+    assert_eq!(
+        "FFFFFFFF".parse::<CodePoint>(),
+        Err(ParseCodePointError::NotACodePoint { value: u32::MAX })
+    );
+}
+
+#[test]
+fn test_name_display_invariants() {
+    use unicode_charname::Name;
+    for v in 0..0x11_0000u32 {
+        if v.name_derivation() != Some(NameDerivation::Explicit) {
+            continue;
+        }
+        let name = v.property_name().unwrap();
+        let s = name.to_string();
+        assert!(!s.contains("  "), "U+{:04X} {:?}", v, s);
+        assert_eq!(s.trim(), s, "U+{:04X} {:?}", v, s);
+        assert!(Name::custom(&s).is_ok(), "U+{:04X} {:?}", v, s);
+        assert_eq!(name.len(), s.len(), "U+{:04X} {:?}", v, s);
+        assert_eq!(
+            name.parts().map(|part| part.as_str()).collect::<String>(),
+            s,
+            "U+{:04X}",
+            v
+        );
+    }
+    // as listed in UnicodeData.txt, including every name with a space next
+    // to a hyphen.
+    for &(v, expected) in &[
+        (0x0F02u32, "TIBETAN MARK GTER YIG MGO -UM RNAM BCAD MA"),
+        (0x0F0A, "TIBETAN MARK BKA- SHOG YIG MGO"),
+        (0x0F13, "TIBETAN MARK CARET -DZUD RTAGS ME LONG CAN"),
+        (0x0F18, "TIBETAN ASTROLOGICAL SIGN -KHYUD PA"),
+        (0x0F39, "TIBETAN MARK TSA -PHRU"),
+        (0x0F60, "TIBETAN LETTER -A"),
+        (0x0FB0, "TIBETAN SUBJOINED LETTER -A"),
+        (0x0FCA, "TIBETAN SYMBOL NOR BU NYIS -KHYIL"),
+        (0x0FD0, "TIBETAN MARK BSKA- SHOG GI MGO RGYAN"),
+        (0x11A29, "ZANABAZAR SQUARE LETTER -A"),
+        (0x11A95, "SOYOMBO FINAL CONSONANT SIGN -A"),
+        (0x11C88, "MARCHEN LETTER -A"),
+        (0x1180, "HANGUL JUNGSEONG O-E"),
+        (0x002D, "HYPHEN-MINUS"),
+        (0x00AD, "SOFT HYPHEN"),
+        (0x0F0C, "TIBETAN MARK DELIMITER TSHEG BSTAR"),
+        (0x1F402, "OX"),
+        (
+            0xFBF9,
+            "ARABIC LIGATURE UIGHUR KIRGHIZ YEH WITH HAMZA ABOVE WITH ALEF MAKSURA ISOLATED FORM",
+        ),
+    ] {
+        assert_eq!(v.property_name().unwrap().to_string(), expected);
+    }
+}
+
+#[test]
+fn test_noncharacters() {
+    use unicode_charname::is_noncharacter;
+    let noncharacters = (0xFDD0..=0xFDEFu32)
+        .chain((0..=16u32).flat_map(|plane| vec![plane << 16 | 0xFFFE, plane << 16 | 0xFFFF]))
+        .collect::<Vec<_>>();
+    assert_eq!(noncharacters.len(), 66);
+    for &v in &noncharacters {
+        let label = format!("<noncharacter-{:04X}>", v);
+        assert!(is_noncharacter(v), "U+{:04X}", v);
+        assert_eq!(v.char_name().unwrap().to_string(), label);
+        assert_eq!(v.property_name(), None, "U+{:04X}", v);
+        assert_eq!(v.code_point_type(), Some(CodePointType::Noncharacter));
+        assert_eq!(
+            v.name_derivation(),
+            Some(NameDerivation::Label(LabelKind::Noncharacter))
+        );
+        assert_eq!(
+            v.char_label_with_block().unwrap().to_string(),
+            label[1..label.len() - 1]
+        );
+    }
+    // U+FDD0 to U+FDEF lie inside Arabic Presentation Forms-A.
+    assert_eq!(0xFDD0u32.block_name(), Some("Arabic Presentation Forms-A"));
+    assert_eq!(0xFDCFu32.code_point_type(), Some(CodePointType::Reserved));
+    assert_eq!(0xFDF0u32.code_point_type(), Some(CodePointType::Graphic));
+    // the private-use planes end right before their noncharacters.
+    assert_eq!(
+        0xFFFFDu32.code_point_type(),
+        Some(CodePointType::PrivateUse)
+    );
+    assert_eq!(
+        0x10FFFDu32.code_point_type(),
+        Some(CodePointType::PrivateUse)
+    );
+    assert_eq!(
+        (0..=0x10FFFFu32).filter(|&v| is_noncharacter(v)).count(),
+        66
+    );
+}
+
+#[test]
+fn test_lookup() {
+    use unicode_charname::lookup;
+    assert_eq!(lookup("LATIN CAPITAL LETTER A"), Some('A'));
+    assert_eq!(lookup("OX"), Some('\u{1F402}'));
+    assert_eq!(lookup("SPACE"), Some(' '));
+    assert_eq!(lookup("TIBETAN LETTER -A"), Some('\u{F60}'));
+    assert_eq!(lookup("HANGUL JUNGSEONG O-E"), Some('\u{1180}'));
+    assert_eq!(lookup("CJK COMPATIBILITY IDEOGRAPH-F900"), Some('\u{F900}'));
+    let longest =
+        "ARABIC LIGATURE UIGHUR KIRGHIZ YEH WITH HAMZA ABOVE WITH ALEF MAKSURA ISOLATED FORM";
+    assert_eq!(lookup(longest), Some('\u{FBF9}'));
+
+    assert_eq!(lookup("LATIN CAPITAL LETTER A "), None);
+    assert_eq!(lookup(" LATIN CAPITAL LETTER A"), None);
+    assert_eq!(lookup("LATIN CAPITAL LETTER"), None);
+    assert_eq!(lookup("latin capital letter a"), None);
+    assert_eq!(lookup("LATIN CAPITAL LETTER A WITH NOTHING"), None);
+    assert_eq!(lookup(""), None);
+    assert_eq!(lookup("<control-0009>"), None);
+    assert_eq!(lookup("\u{0}\u{10FFFF}"), None);
+    assert_eq!(lookup(&"A".repeat(1000)), None);
+    for v in (0..0x11_0000u32).step_by(11) {
+        if v.name_derivation() == Some(NameDerivation::Explicit) {
+            let name = v.property_name().unwrap().to_string();
+            assert_eq!(lookup(&name).map(u32::from), Some(v), "{}", name);
+        }
+    }
+}
+
+#[test]
+fn test_lookup_hangul_syllables() {
+    use unicode_charname::lookup;
+    assert_eq!(lookup("HANGUL SYLLABLE GA"), Some('\u{AC00}'));
+    assert_eq!(lookup("HANGUL SYLLABLE GAG"), Some('\u{AC01}'));
+    assert_eq!(lookup("HANGUL SYLLABLE GAGG"), Some('\u{AC02}'));
+    // the leading consonant IEUNG has an empty short name.
+    assert_eq!(lookup("HANGUL SYLLABLE A"), Some('\u{C544}'));
+    assert_eq!(lookup("HANGUL SYLLABLE HIH"), Some('\u{D7A3}'));
+    assert_eq!(lookup("HANGUL SYLLABLE BBYAE"), Some('\u{BEB4}'));
+    assert_eq!(lookup("HANGUL SYLLABLE SSYI"), Some('\u{C50C}'));
+    assert_eq!(lookup("HANGUL SYLLABLE YEOLB"), Some('\u{C5F7}'));
+
+    assert_eq!(lookup("HANGUL SYLLABLE "), None);
+    assert_eq!(lookup("HANGUL SYLLABLE G"), None);
+    assert_eq!(lookup("HANGUL SYLLABLE GAX"), None);
+    assert_eq!(lookup("HANGUL SYLLABLE GAGGG"), None);
+    assert_eq!(lookup("HANGUL SYLLABLE ga"), None);
+    assert_eq!(lookup("HANGUL SYLLABLE  GA"), None);
+    assert_eq!(lookup("HANGUL SYLLABLE GA "), None);
+    assert_eq!(lookup("HANGUL SYLLABLES GA"), None);
+
+    for v in 0xAC00..=0xD7A3u32 {
+        let name = v.char_name().unwrap().to_string();
+        assert_eq!(lookup(&name).map(u32::from), Some(v), "{}", name);
+    }
+}
+
+#[test]
+fn test_lookup_nr2_names() {
+    use unicode_charname::lookup;
+    for &(name, c) in &[
+        ("CJK UNIFIED IDEOGRAPH-3400", '\u{3400}'),
+        ("CJK UNIFIED IDEOGRAPH-4E00", '\u{4E00}'),
+        ("CJK UNIFIED IDEOGRAPH-9FFC", '\u{9FFC}'),
+        ("CJK UNIFIED IDEOGRAPH-20000", '\u{20000}'),
+        ("CJK UNIFIED IDEOGRAPH-3134A", '\u{3134A}'),
+        ("TANGUT IDEOGRAPH-17000", '\u{17000}'),
+        ("TANGUT IDEOGRAPH-18D08", '\u{18D08}'),
+        // listed in UnicodeData.txt rather than derived.
+        ("CJK COMPATIBILITY IDEOGRAPH-F900", '\u{F900}'),
+        ("KHITAN SMALL SCRIPT CHARACTER-18B00", '\u{18B00}'),
+        ("NUSHU CHARACTER-1B170", '\u{1B170}'),
+        ("TANGUT COMPONENT-001", '\u{18800}'),
+    ] {
+        assert_eq!(lookup(name), Some(c), "{}", name);
+    }
+    for &name in &[
+        // outside the ranges of the prefix.
+        "CJK UNIFIED IDEOGRAPH-FFFF",
+        "CJK UNIFIED IDEOGRAPH-9FFD",
+        "CJK UNIFIED IDEOGRAPH-F900",
+        "CJK UNIFIED IDEOGRAPH-17000",
+        "CJK UNIFIED IDEOGRAPH-0041",
+        "TANGUT IDEOGRAPH-4E00",
+        "CJK UNIFIED IDEOGRAPH-110000",
+        "CJK UNIFIED IDEOGRAPH-FFFFFFFF",
+        // not as written by `char_name`.
+        "CJK UNIFIED IDEOGRAPH-4e00",
+        "CJK UNIFIED IDEOGRAPH-04E00",
+        "CJK UNIFIED IDEOGRAPH-004E00",
+        "CJK UNIFIED IDEOGRAPH-+4E00",
+        "CJK UNIFIED IDEOGRAPH-0x4E00",
+        "CJK UNIFIED IDEOGRAPH- 4E00",
+        "CJK UNIFIED IDEOGRAPH-4E00 ",
+        "CJK UNIFIED IDEOGRAPH-",
+        "CJK UNIFIED IDEOGRAPH4E00",
+        "CJK UNIFIED IDEOGRAPH-100000000",
+    ] {
+        assert_eq!(lookup(name), None, "{}", name);
+    }
+    for v in 0..=0x10FFFFu32 {
+        if v.name_derivation() == Some(unicode_charname::NameDerivation::Nr2) {
+            let name = v.char_name().unwrap().to_string();
+            assert_eq!(lookup(&name).map(u32::from), Some(v), "{}", name);
+        }
+    }
+}
+
+#[test]
+fn test_lookup_aliases() {
+    use unicode_charname::{lookup, lookup_with_aliases};
+    assert_eq!(lookup("ZERO WIDTH JOINER"), Some('\u{200D}'));
+    // abbreviation
+    assert_eq!(lookup("ZWJ"), Some('\u{200D}'));
+    assert_eq!(lookup("NBSP"), Some('\u{A0}'));
+    assert_eq!(lookup("NUL"), Some('\u{0}'));
+    // control
+    assert_eq!(lookup("LINE FEED"), Some('\n'));
+    assert_eq!(lookup("NULL"), Some('\u{0}'));
+    // correction, while the defective name still resolves too.
+    assert_eq!(lookup("LATIN CAPITAL LETTER GHA"), Some('\u{1A2}'));
+    assert_eq!(lookup("LATIN CAPITAL LETTER OI"), Some('\u{1A2}'));
+    // alternate
+    assert_eq!(lookup("BYTE ORDER MARK"), Some('\u{FEFF}'));
+    // figment
+    assert_eq!(lookup("PADDING CHARACTER"), Some('\u{80}'));
+    assert_eq!(lookup("zwj"), None);
+    assert_eq!(lookup("ZWJ "), None);
+
+    assert_eq!(lookup_with_aliases("ZWJ", &[]), None);
+    assert_eq!(
+        lookup_with_aliases("ZERO WIDTH JOINER", &[]),
+        Some('\u{200D}')
+    );
+    assert_eq!(
+        lookup_with_aliases("ZWJ", &[AliasKind::Abbreviation]),
+        Some('\u{200D}')
+    );
+    assert_eq!(
+        lookup_with_aliases("LINE FEED", &[AliasKind::Abbreviation]),
+        None
+    );
+    let no_figments = &[
+        AliasKind::Correction,
+        AliasKind::Control,
+        AliasKind::Alternate,
+        AliasKind::Abbreviation,
+    ];
+    assert_eq!(lookup_with_aliases("PADDING CHARACTER", no_figments), None);
+    assert_eq!(lookup_with_aliases("PAD", no_figments), Some('\u{80}'));
+    assert_eq!(
+        lookup_with_aliases("BYTE ORDER MARK", no_figments),
+        Some('\u{FEFF}')
+    );
+
+    for v in 0..0x11_0000u32 {
+        for &(kind, alias) in v.name_aliases() {
+            assert_eq!(
+                lookup_with_aliases(alias, &[kind]).map(u32::from),
+                Some(v),
+                "{}",
+                alias
+            );
+            // names win over aliases, though UAX #34 keeps them apart.
+            if let Some(c) = lookup_with_aliases(alias, &[]) {
+                assert_eq!(lookup(alias), Some(c), "{}", alias);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_lookup_sequence() {
+    use unicode_charname::{
+        lookup_any, lookup_any_loose, lookup_sequence, lookup_sequence_loose, LookupResult,
+    };
+    assert_eq!(
+        lookup_sequence("KEYCAP NUMBER SIGN"),
+        Some("#\u{FE0F}\u{20E3}")
+    );
+    assert_eq!(lookup_sequence("TAMIL CONSONANT K"), Some("\u{B95}\u{BCD}"));
+    assert_eq!(lookup_sequence("keycap number sign"), None);
+    assert_eq!(lookup_sequence("NUMBER SIGN"), None);
+    assert_eq!(
+        lookup_sequence_loose("keycap_number-sign"),
+        Some("#\u{FE0F}\u{20E3}")
+    );
+    assert_eq!(
+        lookup_sequence_loose("tamil consonant k"),
+        Some("\u{B95}\u{BCD}")
+    );
+    assert_eq!(lookup_sequence_loose("NUMBER SIGN"), None);
+
+    assert_eq!(
+        lookup_any("KEYCAP NUMBER SIGN"),
+        Some(LookupResult::Sequence("#\u{FE0F}\u{20E3}"))
+    );
+    assert_eq!(
+        lookup_any("TAMIL SYLLABLE KAA"),
+        Some(LookupResult::Sequence("\u{B95}\u{BBE}"))
+    );
+    assert_eq!(lookup_any("NUMBER SIGN"), Some(LookupResult::Char('#')));
+    assert_eq!(lookup_any("NBSP"), Some(LookupResult::Char('\u{A0}')));
+    assert_eq!(lookup_any("KEYCAP NUMBER SIGNS"), None);
+    assert_eq!(lookup_any("keycap number sign"), None);
+    assert_eq!(
+        lookup_any_loose("keycap number sign"),
+        Some(LookupResult::Sequence("#\u{FE0F}\u{20E3}"))
+    );
+    assert_eq!(
+        lookup_any_loose("number sign"),
+        Some(LookupResult::Char('#'))
+    );
+    assert_eq!(lookup_any_loose("KEYCAP NUMBER SIGNS"), None);
+    assert_eq!(lookup_any_loose("nbsp"), Some(LookupResult::Char('\u{A0}')));
+}
+
+#[test]
+fn test_lookup_unicode_1_0_name() {
+    use unicode_charname::{lookup, lookup_unicode_1_0_name};
+    // current names win by default.
+    assert_eq!(lookup("BELL"), Some('\u{1F514}'));
+    assert_eq!(lookup_unicode_1_0_name("BELL"), Some('\u{7}'));
+    assert_eq!(lookup("CYRILLIC CAPITAL LETTER E"), Some('\u{42D}'));
+    assert_eq!(
+        lookup_unicode_1_0_name("CYRILLIC CAPITAL LETTER E"),
+        Some('\u{404}')
+    );
+    assert_eq!(
+        lookup_unicode_1_0_name("CYRILLIC CAPITAL LETTER REVERSED E"),
+        Some('\u{42D}')
+    );
+
+    assert_eq!(lookup_unicode_1_0_name("LINE FEED (LF)"), Some('\n'));
+    assert_eq!(
+        lookup_unicode_1_0_name("LATIN SMALL LETTER O HACEK"),
+        Some('\u{1D2}')
+    );
+    assert_eq!(
+        lookup_unicode_1_0_name("NON-SPACING ACUTE"),
+        Some('\u{301}')
+    );
+    assert_eq!(
+        lookup_unicode_1_0_name("FULLWIDTH CLOSING PARENTHESIS"),
+        Some('\u{FF09}')
+    );
+    assert_eq!(lookup("NON-SPACING ACUTE"), None);
+
+    // only Unicode 1.0 names are found.
+    assert_eq!(lookup_unicode_1_0_name("COMBINING ACUTE ACCENT"), None);
+    assert_eq!(lookup_unicode_1_0_name("non-spacing acute"), None);
+    assert_eq!(lookup_unicode_1_0_name(""), None);
+    for v in 0..0x11_0000u32 {
+        if let Some(name) = v.unicode_1_0_name() {
+            assert_eq!(
+                lookup_unicode_1_0_name(name).map(u32::from),
+                Some(v),
+                "{}",
+                name
+            );
+        }
+    }
+}
+
+#[test]
+fn test_names_with_prefix() {
+    use unicode_charname::{names_with_prefix, names_with_prefix_ignore_case};
+    assert_eq!(names_with_prefix("LATIN SMALL LETTER E").count(), 46);
+    assert_eq!(names_with_prefix("LATIN SMALL LETTER E ").count(), 29);
+    assert_eq!(names_with_prefix("HANGUL JUNGSEONG").count(), 95);
+    assert_eq!(names_with_prefix("BOX DRAWINGS LIGHT").count(), 46);
+    let ox: Vec<_> = names_with_prefix("OX")
+        .map(|(c, name)| (c, name.to_string()))
+        .collect();
+    assert_eq!(ox, [('\u{1F402}', "OX".to_string())]);
+    // derived names aren't searched.
+    assert_eq!(names_with_prefix("HANGUL SYLLABLE").count(), 0);
+    assert_eq!(names_with_prefix("CJK UNIFIED IDEOGRAPH").count(), 0);
+    assert_eq!(names_with_prefix("latin small letter e").count(), 0);
+    assert_eq!(names_with_prefix("LATIN SMALL LETTER E\u{0}").count(), 0);
+    assert_eq!(names_with_prefix("\u{FFFF}").count(), 0);
+    assert_eq!(
+        names_with_prefix_ignore_case("latin small letter e").count(),
+        46
+    );
+    assert_eq!(
+        names_with_prefix_ignore_case("Hangul Jungseong").count(),
+        95
+    );
+
+    let jungseong: Vec<_> = names_with_prefix("HANGUL JUNGSEONG").collect();
+    assert_eq!(jungseong[0].0, '\u{1161}');
+    assert_eq!(jungseong[0].1, "HANGUL JUNGSEONG A");
+    // the hyphen of "TIBETAN LETTER -A" sorts before the letters.
+    let tibetan: Vec<_> = names_with_prefix("TIBETAN LETTER ")
+        .map(|(c, _)| c)
+        .collect();
+    assert_eq!(tibetan[0], '\u{F60}');
+    for names in &[jungseong, names_with_prefix("").collect()] {
+        for pair in names.windows(2) {
+            assert!(
+                pair[0].1.to_string() < pair[1].1.to_string(),
+                "{} {}",
+                pair[0].1,
+                pair[1].1
+            );
+        }
+        for (c, name) in names {
+            assert_eq!(c.property_name().as_ref(), Some(name));
+        }
+    }
+    assert_eq!(
+        names_with_prefix("").count(),
+        (0..0x11_0000u32)
+            .filter(|v| v.name_derivation() == Some(NameDerivation::Explicit))
+            .count()
+    );
+}
+
+#[test]
+fn test_names_with_word() {
+    use unicode_charname::names_with_word;
+    let brute_force = |word: &str| -> Vec<u32> {
+        (0..0x11_0000u32)
+            .filter(|v| v.name_derivation() == Some(NameDerivation::Explicit))
+            .filter(|v| v.property_name().unwrap().words().any(|w| w == word))
+            .collect()
+    };
+    for &word in &[
+        "ARROW",
+        "ARROWHEAD",
+        "OX",
+        "E",
+        "O",
+        "LETTER",
+        "FACE",
+        "F900",
+        "2F800",
+        "4E00",
+    ] {
+        let found: Vec<u32> = names_with_word(word).map(|(c, _)| u32::from(c)).collect();
+        assert!(!found.is_empty(), "{}", word);
+        assert_eq!(found, brute_force(word), "{}", word);
+    }
+    for (c, name) in names_with_word("ARROW") {
+        assert!(name.contains_word("ARROW"), "{}", name);
+        assert_eq!(c.property_name(), Some(name));
+    }
+    assert_eq!('\u{2303}'.property_name().unwrap(), "UP ARROWHEAD");
+    assert!(names_with_word("ARROW").all(|(c, _)| c != '\u{2303}'));
+    for &word in &[
+        "",
+        "ARROWZ",
+        "arrow",
+        "ARROW HEAD",
+        "O-E",
+        "-",
+        "@",
+        "0F900",
+        "110000",
+        "FFFFFFFF",
+    ] {
+        assert_eq!(names_with_word(word).count(), 0, "{:?}", word);
+    }
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_names_matching() {
+    use regex::{Regex, RegexBuilder};
+    use unicode_charname::names_matching;
+    let chars = |re: &Regex| -> String { names_matching(re).map(|(c, _)| c).collect() };
+
+    let vowels = Regex::new(r"^LATIN (SMALL|CAPITAL) LETTER [AEIOU]$").unwrap();
+    assert_eq!(chars(&vowels), "AEIOUaeiou");
+    for (c, name) in names_matching(&vowels) {
+        assert_eq!(c.property_name(), Some(name));
+    }
+    // without the end anchor, longer names match too.
+    let with_diacritics = Regex::new(r"^LATIN SMALL LETTER E WITH (ACUTE|GRAVE)").unwrap();
+    assert_eq!(chars(&with_diacritics), "\u{E8}\u{E9}");
+    let snowmen = Regex::new("SNOWMAN").unwrap();
+    assert_eq!(chars(&snowmen), "\u{2603}\u{26C4}\u{26C7}");
+    assert_eq!(chars(&Regex::new("^OX$").unwrap()), "\u{1F402}");
+
+    // names derived by rule.
+    let cjk = Regex::new(r"^CJK UNIFIED IDEOGRAPH-4E..$").unwrap();
+    let found: Vec<u32> = names_matching(&cjk).map(|(c, _)| u32::from(c)).collect();
+    assert_eq!(found, (0x4E00..=0x4EFF).collect::<Vec<_>>());
+    let hangul = Regex::new(r"^HANGUL SYLLABLE GA.?$").unwrap();
+    assert_eq!(chars(&hangul).chars().count(), 15);
+    // labels aren't names.
+    assert_eq!(chars(&Regex::new("control").unwrap()), "");
+
+    // case insensitivity, whether inline or from the builder.
+    let inline = Regex::new(r"(?i)^latin small letter [a-c]$").unwrap();
+    assert_eq!(chars(&inline), "abc");
+    let built = RegexBuilder::new(r"^latin small letter [a-c]$")
+        .case_insensitive(true)
+        .build()
+        .unwrap();
+    assert_eq!(chars(&built), "abc");
+}
+
+#[test]
+fn test_names_matching_glob() {
+    use unicode_charname::names_matching_glob;
+    let chars =
+        |pattern: &str| -> String { names_matching_glob(pattern).map(|(c, _)| c).collect() };
+
+    // without wildcards, only the exact name matches, ignoring case.
+    assert_eq!(chars("LATIN SMALL LETTER A"), "a");
+    assert_eq!(chars("latin Small letter a"), "a");
+    assert_eq!(chars("LATIN SMALL LETTER"), "");
+    assert_eq!(chars("OX"), "\u{1F402}");
+    assert_eq!(chars("HANGUL SYLLABLE GAG"), "\u{AC01}");
+    assert_eq!(chars("TIBETAN LETTER -A"), "\u{F60}");
+    assert_eq!(chars(""), "");
+
+    // `*` matches across spaces, and repeated `*`s are like one.
+    assert_eq!(chars("*SNOWMAN*"), "\u{2603}\u{26C4}\u{26C7}");
+    assert_eq!(chars("**snow**man***"), "\u{2603}\u{26C4}\u{26C7}");
+    assert_eq!(chars("*snowman"), "\u{2603}\u{26C7}");
+    assert_eq!(chars("snowman*"), "\u{2603}\u{26C4}");
+    assert_eq!(chars("*toolbox"), "\u{1F9F0}");
+    assert_eq!(
+        chars("latin * letter a with *ring*"),
+        "\u{C5}\u{E5}\u{1FA}\u{1FB}\u{1E00}\u{1E01}\u{1E9A}"
+    );
+    // the last `*` has to grow past earlier partial matches.
+    assert_eq!(chars("*SMALL LETTER A WITH RING ABOVE"), "\u{E5}");
+
+    // `?` matches exactly one character, a space or hyphen too.
+    assert_eq!(
+        chars("hangul syllable g?g"),
+        "\u{AC01}\u{AC8D}\u{ACE1}\u{AD6D}\u{AE31}"
+    );
+    assert_eq!(chars("CJK UNIFIED IDEOGRAPH?4E00"), "\u{4E00}");
+    assert_eq!(chars("CJK UNIFIED IDEOGRAPH-4E0?").chars().count(), 16);
+    assert_eq!(chars("O?"), "\u{1F402}");
+    assert_eq!(chars("?"), "");
+    assert_eq!(chars("?X"), "\u{1F402}");
+    // labels aren't names.
+    assert_eq!(chars("<control-000?>"), "");
+    assert_eq!(chars("control-*"), "");
+
+    for (c, name) in names_matching_glob("*ARROW") {
+        assert!(name.ends_with("ARROW"), "{}", name);
+        assert_eq!(c.property_name(), Some(name));
+    }
+}
+
+#[test]
+fn test_closest_names() {
+    use unicode_charname::closest_names;
+    for &(query, c, score) in &[
+        // single-letter typos.
+        ("GREK SMALL LETTER ALPHA", '\u{3B1}', 1),
+        ("LATIN SMALL LETER A", 'a', 1),
+        ("SNOWMN", '\u{2603}', 1),
+        ("zero width joinr", '\u{200D}', 1),
+        // a transposition.
+        ("GREEK SMALL LETTER APLHA", '\u{3B1}', 1),
+        // a missing word.
+        ("GREEK LETTER ALPHA", '\u{3B1}', 1),
+        ("ZERO JOINER", '\u{200D}', 1),
+        ("LATIN CAPITAL A", 'A', 1),
+        // an exact match.
+        ("BLACK HEART SUIT", '\u{2665}', 0),
+    ] {
+        let suggestions = closest_names(query, 5);
+        assert_eq!(suggestions.len(), 5, "{}", query);
+        let (first, name, first_score) = &suggestions[0];
+        assert_eq!((*first, *first_score), (c, score), "{} {}", query, name);
+        assert_eq!(c.property_name().as_ref(), Some(name));
+        assert!(
+            suggestions[1..].iter().all(|&(_, _, s)| s >= score),
+            "{}",
+            query
+        );
+        assert!(
+            suggestions.windows(2).all(|pair| pair[0].2 <= pair[1].2),
+            "{}",
+            query
+        );
+    }
+    assert!(closest_names("GREK SMALL LETTER ALPHA", 0).is_empty());
+    let names: Vec<_> = closest_names("BLACK HEART SUIT", 2)
+        .into_iter()
+        .map(|(_, name, score)| (name.to_string(), score))
+        .collect();
+    assert_eq!(
+        names,
+        [
+            ("BLACK HEART SUIT".to_string(), 0),
+            ("BLACK HEART".to_string(), 1)
+        ]
+    );
+}
+
+#[test]
+fn test_lookup_ignore_case() {
+    use unicode_charname::lookup_ignore_case;
+    assert_eq!(lookup_ignore_case("LATIN CAPITAL LETTER A"), Some('A'));
+    assert_eq!(lookup_ignore_case("latin capital letter a"), Some('A'));
+    assert_eq!(lookup_ignore_case("Latin Capital Letter A"), Some('A'));
+    assert_eq!(lookup_ignore_case("zErO wIdTh JoInEr"), Some('\u{200D}'));
+    assert_eq!(lookup_ignore_case("tibetan letter -a"), Some('\u{F60}'));
+    assert_eq!(lookup_ignore_case("hangul jungseong o-e"), Some('\u{1180}'));
+    assert_eq!(lookup_ignore_case("hangul jungseong oe"), Some('\u{116C}'));
+    assert_eq!(lookup_ignore_case("hangul syllable gag"), Some('\u{AC01}'));
+    assert_eq!(
+        lookup_ignore_case("cjk unified ideograph-4e00"),
+        Some('\u{4E00}')
+    );
+    assert_eq!(lookup_ignore_case("zwj"), Some('\u{200D}'));
+    let longest =
+        "arabic ligature uighur kirghiz yeh with hamza above with alef maksura isolated form";
+    assert_eq!(lookup_ignore_case(longest), Some('\u{FBF9}'));
+
+    // spaces, hyphens and underscores are still strict.
+    assert_eq!(lookup_ignore_case("ZERO-WIDTH JOINER"), None);
+    assert_eq!(lookup_ignore_case("zero-width joiner"), None);
+    assert_eq!(lookup_ignore_case("ZERO WIDTH  JOINER"), None);
+    assert_eq!(lookup_ignore_case("zero_width_joiner"), None);
+    assert_eq!(lookup_ignore_case("zerowidthjoiner"), None);
+    assert_eq!(lookup_ignore_case("hangul jungseong o e"), None);
+    assert_eq!(lookup_ignore_case("tibetan letter a"), Some('\u{F68}'));
+    assert_eq!(lookup_ignore_case(" latin capital letter a"), None);
+    // only ASCII case is ignored.
+    assert_eq!(lookup_ignore_case("LATIN SMALL LETTER \u{212A}"), None);
+    assert_eq!(lookup_ignore_case(""), None);
+    assert_eq!(lookup_ignore_case(&"a".repeat(1000)), None);
+}
+
+#[test]
+fn test_names_search_in_range() {
+    use unicode_charname::{
+        names_containing, names_containing_in, names_with_prefix, names_with_prefix_in,
+        names_with_word, names_with_word_in,
+    };
+
+    #[allow(clippy::reversed_empty_ranges)]
+    let ranges = [
+        0x2190..=0x21FF,
+        0..=0xFFFF,
+        0x10000..=0x1FFFF,
+        0x2195..=0x2195,
+        0x1F600..=0x1F64F,
+        0x21FF..=0x2190,
+    ];
+    for range in ranges {
+        let keep = |(c, _): &(char, _)| range.contains(&(*c as u32));
+        for needle in ["ARROW", "FACE", "DOWN", "SNOWMAN", "ARROWZ"] {
+            assert!(
+                names_containing_in(needle, range.clone())
+                    .eq(names_containing(needle).filter(keep)),
+                "{} in {:?}",
+                needle,
+                range
+            );
+            assert!(
+                names_with_word_in(needle, range.clone()).eq(names_with_word(needle).filter(keep)),
+                "{} in {:?}",
+                needle,
+                range
+            );
+        }
+        for prefix in ["UPWARDS", "GRINNING", "LATIN SMALL", "ARROWZ"] {
+            let in_range: Vec<_> = names_with_prefix_in(prefix, range.clone()).collect();
+            let filtered: Vec<_> = names_with_prefix(prefix).filter(keep).collect();
+            assert_eq!(in_range, filtered, "{} in {:?}", prefix, range);
+        }
+    }
+    let v: Vec<_> = names_with_word_in("4E00", 0x1F200..=0x1F2FF).collect();
+    assert_eq!(v, names_with_word("4E00").collect::<Vec<_>>());
+    assert_eq!(names_with_word_in("4E00", 0..=0xFFFF).count(), 0);
+}
+
+#[test]
+fn test_search_block_filter() {
+    use unicode_charname::{names_containing_in, search, Block, Filter};
+
+    for (name, range) in [
+        ("Braille Patterns", 0x2800..=0x28FF),
+        ("braille_patterns", 0x2800..=0x28FF),
+        ("BRAILLEPATTERNS", 0x2800..=0x28FF),
+        ("Latin-1 Supplement", 0x80..=0xFF),
+        ("latin 1 supplement", 0x80..=0xFF),
+        ("Arabic Presentation Forms-A", 0xFB50..=0xFDFF),
+        ("arabic_presentation_forms_a", 0xFB50..=0xFDFF),
+        ("CJK Unified Ideographs Extension B", 0x20000..=0x2A6DF),
+    ] {
+        assert_eq!(Filter::Block(name).range(), Ok(range.clone()), "{}", name);
+        assert_eq!(Block::from_name(name).unwrap().range(), range);
+        let found: Vec<_> = search("DOT", Filter::Block(name)).unwrap().collect();
+        assert_eq!(found, names_containing_in("DOT", range).collect::<Vec<_>>());
+    }
+    let braille = search("DOT", Filter::Block("Braille Patterns")).unwrap();
+    assert_eq!(braille.count(), 255);
+    let latin: String = search("DOT", Filter::Block("latin-1 supplement"))
+        .unwrap()
+        .map(|(c, _)| c)
+        .collect();
+    assert_eq!(latin, "\u{B7}");
+    assert_eq!(
+        search("DOT", Filter::All).unwrap().count(),
+        unicode_charname::names_containing("DOT").count()
+    );
+    assert_eq!(
+        search("ARROW", Filter::Range(0x2190..=0x21FF))
+            .unwrap()
+            .count(),
+        names_containing_in("ARROW", 0x2190..=0x21FF).count()
+    );
+
+    for name in [
+        "Braile Patterns",
+        "",
+        "Patterns",
+        "Braille Patterns Extended",
+    ] {
+        let err = search("DOT", Filter::Block(name)).err().unwrap();
+        assert_eq!(err.name(), name);
+        assert_eq!(Block::from_name(name), None);
+    }
+    assert_eq!(
+        Filter::Block("Arrowz").range().unwrap_err().to_string(),
+        "unknown block name \"Arrowz\""
+    );
+}
+
+#[test]
+fn test_name_index() {
+    use unicode_charname::{lookup, lookup_loose, NameIndex};
+
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<NameIndex>();
+
+    let index = NameIndex::new();
+    let mut count = 0;
+    for c in (0..=0x10FFFF).filter_map(std::char::from_u32) {
+        let name = match c.property_name() {
+            Some(name) => name.to_string(),
+            None => continue,
+        };
+        assert_eq!(index.exact(&name), Some(c), "{}", name);
+        assert_eq!(index.loose(&name), Some(c), "{}", name);
+        assert_eq!(index.loose(&name.to_lowercase()), Some(c), "{}", name);
+        if count % 97 == 0 {
+            assert!(index.prefix(&name).any(|(d, _)| d == c), "{}", name);
+        }
+        count += 1;
+    }
+    assert_eq!(count, 143859);
+
+    for name in [
+        "ZWJ",
+        "NUL",
+        "LINE FEED",
+        "BYTE ORDER MARK",
+        "PRESENTATION FORM FOR VERTICAL RIGHT WHITE LENTICULAR BRAKCET",
+        "BELL",
+        "HANGUL SYLLABLE GAG",
+        "ox",
+        "NOT A NAME",
+        "",
+    ] {
+        assert_eq!(index.exact(name), lookup(name), "{}", name);
+    }
+    for name in [
+        "zero width joiner",
+        "latin small letter a",
+        "hangul jungseong o-e",
+        "hangul jungseong oe",
+        "cjk unified ideograph 4e00",
+        "tibetan letter a",
+        "line feed",
+        "zwj",
+        "not a name",
+    ] {
+        assert_eq!(index.loose(name), lookup_loose(name), "{}", name);
+    }
+    // aliases are matched loosely too.
+    assert_eq!(index.loose("zwj"), Some('\u{200D}'));
+    assert_eq!(index.loose("byte-order mark"), Some('\u{FEFF}'));
+
+    let prefixed: Vec<_> = index.prefix("LATIN SMALL LETTER E ").collect();
+    let listed: Vec<_> = unicode_charname::names_with_prefix("LATIN SMALL LETTER E ").collect();
+    assert_eq!(prefixed, listed);
+    // GAG, GAGG and GAGS.
+    assert_eq!(index.prefix("HANGUL SYLLABLE GAG").count(), 3);
+    assert_eq!(index.prefix("CJK UNIFIED IDEOGRAPH-4E0").count(), 16);
+    assert_eq!(index.prefix("NOT A NAME").count(), 0);
+}
+
+#[cfg(feature = "fst")]
+#[test]
+fn test_fst_matches_tables() {
+    use std::collections::BTreeMap;
+    use unicode_charname::FstIndex;
+
+    // the keys scripts/fst-gen writes, rebuilt from the tables.
+    let mut expected = BTreeMap::new();
+    for c in (0..=0x10FFFF).filter_map(std::char::from_u32) {
+        if let Some(name) = c.property_name() {
+            expected.insert(name.to_string(), c);
+        }
+    }
+    for c in (0..=0x10FFFF).filter_map(std::char::from_u32) {
+        for &(_, alias) in c.name_aliases() {
+            expected.entry(alias.to_string()).or_insert(c);
+        }
+    }
+    let index = FstIndex::new();
+    assert_eq!(index.len(), expected.len());
+    assert!(
+        index.prefix_iter("").eq(expected.into_iter()),
+        "src/names.fst is out of date, rebuild it with scripts/fst-gen"
+    );
+}
+
+#[cfg(feature = "fst")]
+#[test]
+fn test_fst_index() {
+    use unicode_charname::{lookup, names_with_prefix, FstIndex};
+
+    let index = FstIndex::new();
+    let mut count = 0;
+    for c in (0..=0x10FFFF).filter_map(std::char::from_u32) {
+        if let Some(name) = c.property_name() {
+            let name = name.to_string();
+            assert_eq!(index.get(&name), Some(c), "{}", name);
+            assert_eq!(index.get(&name), lookup(&name), "{}", name);
+            count += 1;
+        }
+        for &(_, alias) in c.name_aliases() {
+            assert_eq!(index.get(alias), lookup(alias), "{}", alias);
+        }
+    }
+    assert_eq!(count, 143859);
+    assert!(index.len() > count);
+    for name in ["ox", "NOT A NAME", "", "SNOWMA", "SNOWMAN "] {
+        assert_eq!(index.get(name), None, "{}", name);
+    }
+
+    // leaving out the aliases, the FST finds the names `names_with_prefix`
+    // does, in the same order.
+    for prefix in ["LATIN SMALL LETTER E ", "BOX DRAWINGS LIGHT", "SNOWMAN"] {
+        let from_fst: Vec<_> = index
+            .prefix_iter(prefix)
+            .filter(|(name, c)| c.property_name().unwrap() == name.as_str())
+            .map(|(_, c)| c)
+            .collect();
+        let listed: Vec<_> = names_with_prefix(prefix).map(|(c, _)| c).collect();
+        assert_eq!(from_fst, listed, "{}", prefix);
+    }
+    let ideographs: Vec<_> = index
+        .prefix_iter("CJK UNIFIED IDEOGRAPH-4E0")
+        .map(|(_, c)| c)
+        .collect();
+    assert_eq!(ideographs, ('\u{4E00}'..='\u{4E0F}').collect::<Vec<_>>());
+    let (name, c) = index.prefix_iter("ZW").next().unwrap();
+    assert_eq!((name.as_str(), c), ("ZWJ", '\u{200D}'));
+    assert_eq!(index.prefix_iter("NOT A NAME").count(), 0);
+
+    let fuzzy: Vec<_> = index.fuzzy("GREK SMALL LETTER ALPHA", 1).unwrap().collect();
+    assert_eq!(fuzzy, [("GREEK SMALL LETTER ALPHA".to_string(), '\u{3B1}')]);
+    let fuzzy: Vec<_> = index.fuzzy("HANGUL SYLLABLE GAG", 1).unwrap().collect();
+    assert!(fuzzy.contains(&("HANGUL SYLLABLE GAG".to_string(), '\u{AC01}')));
+    assert!(fuzzy.contains(&("HANGUL SYLLABLE GAGG".to_string(), '\u{AC02}')));
+    assert!(fuzzy.iter().all(|(name, c)| lookup(name) == Some(*c)));
+    assert_eq!(index.fuzzy("XYZZY QUUX", 2).unwrap().count(), 0);
+}
+
+#[test]
+fn test_unescape_named() {
+    use unicode_charname::{unescape_named, unescape_named_loose, UnescapeErrorKind};
+
+    for (s, unescaped) in [
+        ("", ""),
+        ("no escapes", "no escapes"),
+        (r"\N{SNOWMAN}", "\u{2603}"),
+        (
+            r"snow: \N{SNOWMAN}\N{VARIATION SELECTOR-16}!",
+            "snow: \u{2603}\u{FE0F}!",
+        ),
+        (
+            r"\N{LATIN SMALL LETTER A}\N{COMBINING ACUTE ACCENT}",
+            "a\u{301}",
+        ),
+        // aliases, and names derived by rule.
+        (r"\N{ZWJ}\N{NBSP}\N{LINE FEED}", "\u{200D}\u{A0}\n"),
+        (
+            r"\N{HANGUL SYLLABLE GAG}\N{CJK UNIFIED IDEOGRAPH-4E00}",
+            "\u{AC01}\u{4E00}",
+        ),
+        // named sequences.
+        (r"\N{KEYCAP DIGIT ONE}", "1\u{FE0F}\u{20E3}"),
+        (r"\N{TAMIL CONSONANT K}.", "\u{B95}\u{BCD}."),
+        // backslashes.
+        (r"\\N{SNOWMAN}", r"\N{SNOWMAN}"),
+        (r"\\\N{SNOWMAN}", "\\\u{2603}"),
+        (r"\\\\N{SNOWMAN}", r"\\N{SNOWMAN}"),
+        (r"a\b\n\u{41}\", r"a\b\n\u{41}\"),
+        (r"\é\N{OX}", "\\é\u{1F402}"),
+        (r"} \N{OX} {", "} \u{1F402} {"),
+    ] {
+        assert_eq!(unescape_named(s).as_deref(), Ok(unescaped), "{}", s);
+    }
+
+    for (s, kind, span, name) in [
+        (
+            r"\N{SNOWMAN",
+            UnescapeErrorKind::Unterminated,
+            0..10,
+            "SNOWMAN",
+        ),
+        (r"ab \N{", UnescapeErrorKind::Unterminated, 3..6, ""),
+        (
+            r"\N{OX}\N{SNOWMAN",
+            UnescapeErrorKind::Unterminated,
+            6..16,
+            "SNOWMAN",
+        ),
+        (
+            r"\N{SNOW{MAN}}",
+            UnescapeErrorKind::NestedBrace,
+            0..8,
+            "SNOW",
+        ),
+        (r"\N{{SNOWMAN}}", UnescapeErrorKind::NestedBrace, 0..4, ""),
+        (r"\N{SNOW{MAN", UnescapeErrorKind::NestedBrace, 0..8, "SNOW"),
+        (r"\NSNOWMAN", UnescapeErrorKind::MissingBrace, 0..2, ""),
+        (r"x\N", UnescapeErrorKind::MissingBrace, 1..3, ""),
+        (r"\N{}", UnescapeErrorKind::UnknownName, 0..4, ""),
+        (
+            r"é \N{SNOWMEN}",
+            UnescapeErrorKind::UnknownName,
+            3..14,
+            "SNOWMEN",
+        ),
+        (
+            r"\N{snowman}",
+            UnescapeErrorKind::UnknownName,
+            0..11,
+            "snowman",
+        ),
+        (
+            r"\N{ SNOWMAN}",
+            UnescapeErrorKind::UnknownName,
+            0..12,
+            " SNOWMAN",
+        ),
+        (r"\\\N{OXEN}", UnescapeErrorKind::UnknownName, 2..10, "OXEN"),
+    ] {
+        let err = unescape_named(s).unwrap_err();
+        assert_eq!(err.kind(), kind, "{}", s);
+        assert_eq!(err.span(), span, "{}", s);
+        assert_eq!(err.name(), name, "{}", s);
+    }
+    assert_eq!(
+        unescape_named(r"snow: \N{SNOWMEN}")
+            .unwrap_err()
+            .to_string(),
+        "unknown character name \"SNOWMEN\" at bytes 6..17"
+    );
+    assert_eq!(
+        unescape_named(r"\N{SNOWMAN").unwrap_err().to_string(),
+        "unterminated `\\N{` escape at bytes 0..10"
+    );
+
+    for (s, unescaped) in [
+        (r"\N{snowman}", "\u{2603}"),
+        (r"\N{Latin_Small_Letter_A}", "a"),
+        (r"\N{zwj}\N{zero-width joiner}", "\u{200D}\u{200D}"),
+        (r"\N{byte order mark}", "\u{FEFF}"),
+        (r"\N{hangul syllable gag}", "\u{AC01}"),
+        (r"\N{keycap digit one}", "1\u{FE0F}\u{20E3}"),
+        (r"\\N{snowman}", r"\N{snowman}"),
+    ] {
+        assert_eq!(unescape_named_loose(s).as_deref(), Ok(unescaped), "{}", s);
+    }
+    let err = unescape_named_loose(r"\N{snow men}").unwrap_err();
+    assert_eq!(err.kind(), UnescapeErrorKind::UnknownName);
+    assert_eq!(
+        unescape_named_loose(r"\N{snow{man}}").unwrap_err().kind(),
+        UnescapeErrorKind::NestedBrace
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_name_serde_round_trip() {
+    use unicode_charname::{code_point_label, LabelStyle, Name, NameOptions};
+
+    let uppercase = LabelStyle {
+        angle_brackets: true,
+        uppercase: true,
+    };
+    let uppercase_bare = LabelStyle {
+        angle_brackets: false,
+        uppercase: true,
+    };
+    let names = vec![
+        Name::custom("MY PRIVATE CHARACTER").unwrap(),
+        Name::custom("CJK UNIFIED IDEOGRAPH-0041").unwrap(),
+        0xFE18u32.corrected_name().unwrap(),
+        0x0Au32.readable_name().unwrap(),
+        0x200Du32.short_display().unwrap(),
+        NameOptions::new()
+            .control_aliases(true)
+            .name_of(0x85)
+            .unwrap(),
+        NameOptions::new()
+            .label_style(LabelStyle::BARE)
+            .name_of(0x81)
+            .unwrap(),
+        NameOptions::new()
+            .label_style(uppercase)
+            .name_of(0x81)
+            .unwrap(),
+        NameOptions::new()
+            .label_style(uppercase_bare)
+            .name_of(0xFFFF)
+            .unwrap(),
+        Name::EMPTY,
+        0x0378u32.char_label_with_block().unwrap(),
+        0xE0080u32.char_label_with_block().unwrap(),
+        code_point_label(0xD800, uppercase).unwrap(),
+        code_point_label(0xE000, uppercase_bare).unwrap(),
+    ];
+    for name in names {
+        let json = serde_json::to_string(&name).unwrap();
+        let back: Name = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, name, "{}", json);
+        assert_eq!(back.kind(), name.kind(), "{}", json);
+        assert_eq!(back.code_point(), name.code_point(), "{}", json);
+        assert_eq!(back.to_string(), name.to_string());
+    }
+}
+
+#[test]
+fn test_lookup_loose() {
+    use unicode_charname::lookup_loose;
+    for &(name, c) in &[
+        ("ZERO WIDTH JOINER", '\u{200D}'),
+        ("zero width joiner", '\u{200D}'),
+        ("ZERO-WIDTH_JOINER", '\u{200D}'),
+        ("zerowidthjoiner", '\u{200D}'),
+        ("  Zero\tWidth  Joiner ", '\u{200D}'),
+        ("latin_small_letter_a", 'a'),
+        ("LATIN SMALL LETTER \u{212A}", 'k'),
+        ("ox", '\u{1F402}'),
+        // the hyphen of U+1180 is significant.
+        ("HANGUL JUNGSEONG OE", '\u{116C}'),
+        ("hangul jungseong o e", '\u{116C}'),
+        ("HANGUL JUNGSEONG O-E", '\u{1180}'),
+        ("hangul_jungseong_o-e", '\u{1180}'),
+        // and so are hyphens that aren't medial.
+        ("TIBETAN LETTER -A", '\u{0F60}'),
+        ("tibetan letter -a", '\u{0F60}'),
+        ("TIBETAN LETTER - A", '\u{0F60}'),
+        ("TIBETAN LETTER A", '\u{0F68}'),
+        ("TIBETAN LETTER-A", '\u{0F68}'),
+        ("hangul syllable gag", '\u{AC01}'),
+        ("HANGULSYLLABLEGA-G", '\u{AC01}'),
+        ("cjk unified ideograph-4e00", '\u{4E00}'),
+        ("CJK_UNIFIED_IDEOGRAPH_4E00", '\u{4E00}'),
+        ("tangut ideograph 17000", '\u{17000}'),
+        ("cjk compatibility ideograph f900", '\u{F900}'),
+        // aliases are found too.
+        ("line feed", '\n'),
+        ("zwj", '\u{200D}'),
+        ("Byte-Order Mark", '\u{FEFF}'),
+        (
+            "presentation form for vertical right white lenticular brakcet",
+            '\u{FE18}',
+        ),
+    ] {
+        assert_eq!(lookup_loose(name), Some(c), "{:?}", name);
+    }
+    for &name in &[
+        "",
+        "ZERO WIDTH JOINERS",
+        "HANGUL JUNGSEONG O--E",
+        "CJK UNIFIED IDEOGRAPH-04E00",
+        "CJK UNIFIED IDEOGRAPH-FFFF",
+        "<control-0009>",
+        "ZERO WIDTH JOIN\u{00C9}R",
+    ] {
+        assert_eq!(lookup_loose(name), None, "{:?}", name);
+    }
+}
+
+#[test]
+#[allow(clippy::comparison_to_empty)]
+fn test_name_eq_str() {
+    let name = 'A'.char_name().unwrap();
+    assert!(name == "LATIN CAPITAL LETTER A");
+    assert!(name == *"LATIN CAPITAL LETTER A");
+    assert!(name != "LATIN CAPITAL LETTER");
+    assert!(name != "LATIN CAPITAL LETTER AB");
+    assert!(name != "LATIN CAPITAL LETTER A ");
+    assert!(name != "LATIN  CAPITAL LETTER A");
+    assert!(name != "");
+
+    assert!(0x1F402u32.char_name().unwrap() == "OX");
+    assert!(0x1180u32.char_name().unwrap() == "HANGUL JUNGSEONG O-E");
+    assert!(0x1180u32.char_name().unwrap() != "HANGUL JUNGSEONG O E");
+    assert!(0xFA0Eu32.char_name().unwrap() == "CJK COMPATIBILITY IDEOGRAPH-FA0E");
+    assert!(0xFA0Eu32.char_name().unwrap() != "CJK COMPATIBILITY IDEOGRAPH-FA0F");
+    assert!(0xD4DBu32.char_name().unwrap() == "HANGUL SYLLABLE PWILH");
+    assert!(0x4E00u32.char_name().unwrap() == "CJK UNIFIED IDEOGRAPH-4E00");
+    assert!(0x0081u32.char_name().unwrap() == "<control-0081>");
+    assert!(0x0081u32.char_name().unwrap() != "control-0081");
+}
+
+#[test]
+fn test_name_eq_ignore_ascii_case() {
+    let a = 'A'.char_name().unwrap();
+    assert!(a.eq_ignore_ascii_case("latin capital letter a"));
+    assert!(a.eq_ignore_ascii_case("LATIN CAPITAL LETTER A"));
+    assert!(a.eq_ignore_ascii_case("Latin Capital lETTER a"));
+    assert!(!a.eq_ignore_ascii_case("latin capital letter  a"));
+    assert!(!a.eq_ignore_ascii_case("latin capital letter a "));
+    assert!(!a.eq_ignore_ascii_case("latin capital-letter a"));
+    assert!(!a.eq_ignore_ascii_case("latin capital letter b"));
+    assert!(!a.eq_ignore_ascii_case("latin capital letter"));
+    assert!(!a.eq_ignore_ascii_case(""));
+    assert!(!a.eq_ignore_ascii_case("latin capital letter \u{e9}"));
+
+    let o_e = 0x1180u32.char_name().unwrap();
+    assert!(o_e.eq_ignore_ascii_case("hangul jungseong o-e"));
+    assert!(!o_e.eq_ignore_ascii_case("hangul jungseong o e"));
+    assert!(0x4E00u32
+        .char_name()
+        .unwrap()
+        .eq_ignore_ascii_case("cjk unified ideograph-4e00"));
+    assert!(0x0081u32
+        .char_name()
+        .unwrap()
+        .eq_ignore_ascii_case("<CONTROL-0081>"));
+    assert!(0xF900u32
+        .char_name()
+        .unwrap()
+        .eq_ignore_ascii_case("cjk compatibility ideograph-f900"));
+}
+
+fn hash_of<T: std::hash::Hash + ?Sized>(v: &T) -> u64 {
+    use std::hash::Hasher;
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    v.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_name_eq_hash() {
+    use std::collections::HashSet;
+
+    let a = 'A'.char_name().unwrap();
+    assert!(a == 0x41u32.char_name().unwrap());
+    assert_eq!(hash_of(&a), hash_of(&0x41u32.char_name().unwrap()));
+    assert!(a != 'B'.char_name().unwrap());
+    assert!(a != 0x4E00u32.char_name().unwrap());
+
+    // names hash like their rendered text, whatever their representation.
+    assert_eq!(hash_of(&a), hash_of("LATIN CAPITAL LETTER A"));
+    let cjk = 0x4E00u32.char_name().unwrap();
+    assert_eq!(hash_of(&cjk), hash_of("CJK UNIFIED IDEOGRAPH-4E00"));
+    let control = 0x0081u32.char_name().unwrap();
+    assert_eq!(hash_of(&control), hash_of("<control-0081>"));
+
+    let set: HashSet<_> = ['A', 'B', 'A', '\u{4E00}', '\u{4E00}']
+        .iter()
+        .map(|c| c.char_name().unwrap())
+        .collect();
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(&'B'.char_name().unwrap()));
+    assert!(!set.contains(&'C'.char_name().unwrap()));
+}
+
+#[test]
+fn test_name_eq_across_representations() {
+    use unicode_charname::Name;
+
+    // custom names are stored as generated text.
+    let generated = |s: &str| Name::custom(s).unwrap();
+    for &v in &[0x41u32, 0x1180, 0xFBF9, 0xF900, 0xFA0E, 0x2F800, 0x1F402] {
+        let name = v.char_name().unwrap();
+        let other = generated(&name.to_string());
+        assert!(name == other, "U+{:04X}", v);
+        assert!(other == name, "U+{:04X}", v);
+        assert_eq!(hash_of(&name), hash_of(&other));
+    }
+    // names containing the code point placeholder.
+    assert!(0xF900u32.char_name().unwrap() == generated("CJK COMPATIBILITY IDEOGRAPH-F900"));
+    assert!(0xF900u32.char_name().unwrap() != generated("CJK COMPATIBILITY IDEOGRAPH-F901"));
+    assert!(0xF900u32.char_name().unwrap() != 0xF901u32.char_name().unwrap());
+    assert!(0xF900u32.char_name().unwrap() != generated("CJK COMPATIBILITY IDEOGRAPH-2F800"));
+    assert!('A'.char_name().unwrap() != generated("LATIN CAPITAL LETTER B"));
+    assert!('A'.char_name().unwrap() != generated("LATIN CAPITAL LETTER AE"));
+    assert_eq!(
+        0x4E00u32.char_name().unwrap(),
+        generated("CJK UNIFIED IDEOGRAPH-4E00")
+    );
+}
+
+#[test]
+fn test_name_ord() {
+    let a = 'A'.char_name().unwrap();
+    let ae = 'Æ'.char_name().unwrap();
+    let b = 'B'.char_name().unwrap();
+    assert!(a < ae);
+    assert!(ae < b);
+    assert!(a < b);
+    assert_eq!(a.cmp(&'A'.char_name().unwrap()), std::cmp::Ordering::Equal);
+
+    let sample: Vec<_> = (0x0u32..0x1000)
+        .chain(0xAC00..0xAD00)
+        .chain(0x1F400..0x1F500)
+        .step_by(3)
+        .filter_map(|v| v.char_name())
+        .collect();
+    for pair in sample.windows(2) {
+        assert_eq!(
+            pair[0].cmp(&pair[1]),
+            pair[0].to_string().cmp(&pair[1].to_string())
+        );
+        assert_eq!(
+            pair[1].cmp(&pair[0]),
+            pair[1].to_string().cmp(&pair[0].to_string())
+        );
+    }
+    let mut sorted = sample.clone();
+    sorted.sort();
+    let mut sorted_strings: Vec<_> = sample.iter().map(|x| x.to_string()).collect();
+    sorted_strings.sort();
+    assert_eq!(
+        sorted.iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+        sorted_strings
+    );
+}
+
+#[test]
+fn test_name_debug() {
+    assert_eq!(
+        format!("{:?}", 'A'.char_name().unwrap()),
+        "Name(\"LATIN CAPITAL LETTER A\")"
+    );
+    assert_eq!(
+        format!("{:?}", 0xAC00u32.char_name().unwrap()),
+        "Name(\"HANGUL SYLLABLE GA\")"
+    );
+    assert_eq!(
+        format!("{:?}", 0x1029Fu32.char_name()),
+        "Some(Name(\"<reserved-1029F>\"))"
+    );
+    assert_eq!(
+        format!("{:#?}", 'A'.char_name().unwrap()),
+        "Name(\n    \"LATIN CAPITAL LETTER A\",\n)"
+    );
+}
+
+#[test]
+fn test_name_len() {
+    assert_eq!('A'.char_name().unwrap().len(), 22);
+    assert_eq!(0x1F402u32.char_name().unwrap().len(), 2);
+    assert_eq!(0x20000u32.char_name().unwrap().len(), 27);
+    for v in (0..=0x10FFFFu32).step_by(7).chain(0xFA0E..0xFA30) {
+        let name = v.char_name().unwrap();
+        assert_eq!(name.len(), name.to_string().len(), "U+{:04X}", v);
+    }
+}
+
+#[test]
+fn test_name_empty() {
+    use unicode_charname::Name;
+
+    assert!(Name::EMPTY.is_empty());
+    assert!(Name::default().is_empty());
+    assert_eq!(Name::EMPTY.len(), 0);
+    assert_eq!(Name::EMPTY.to_string(), "");
+    assert_eq!(format!("{:#}", Name::EMPTY), "");
+    assert_eq!(Name::EMPTY.words().count(), 0);
+    assert!(Name::default() == Name::EMPTY);
+    assert!(0x110000u32.char_name().unwrap_or_default().is_empty());
+    for v in (0..=0x10FFFFu32).step_by(13) {
+        assert!(!v.char_name().unwrap().is_empty(), "U+{:04X}", v);
+    }
+    for &v in &[0x41u32, 0xAC00, 0x4E00, 0x0081, 0x10FFFF] {
+        assert!(!v.char_name().unwrap().is_empty(), "U+{:04X}", v);
+    }
+}
+
+#[test]
+fn test_name_max_len() {
+    use unicode_charname::{longest_name, NAME_MAX_LEN};
+
+    let (v, name) = longest_name();
+    assert_eq!(name.len(), NAME_MAX_LEN);
+    assert_eq!(Some(name), v.char_name());
+    let max_len = (0..=0x10FFFFu32)
+        .map(|v| v.char_name().unwrap().len())
+        .max()
+        .unwrap();
+    assert_eq!(max_len, NAME_MAX_LEN);
+}
+
+#[test]
+fn test_name_words() {
+    fn words(v: u32) -> Vec<String> {
+        v.char_name()
+            .unwrap()
+            .words()
+            .map(|x| x.to_string())
+            .collect()
+    }
+    assert_eq!(words(0x41), ["LATIN", "CAPITAL", "LETTER", "A"]);
+    assert_eq!(words(0x1F402), ["OX"]);
+    assert_eq!(words(0x1180), ["HANGUL", "JUNGSEONG", "O", "E"]);
+    assert_eq!(words(0x0F60), ["TIBETAN", "LETTER", "A"]);
+    assert_eq!(words(0x10000), ["LINEAR", "B", "SYLLABLE", "B008", "A"]);
+    assert_eq!(words(0xFA0E), ["CJK", "COMPATIBILITY", "IDEOGRAPH", "FA0E"]);
+    assert_eq!(words(0xD4DB), ["HANGUL", "SYLLABLE", "PWILH"]);
+    assert_eq!(words(0x4E00), ["CJK", "UNIFIED", "IDEOGRAPH", "4E00"]);
+    assert_eq!(words(0x0081), ["control", "0081"]);
+    assert_eq!(words(0xE000), ["private", "use", "E000"]);
+
+    for v in (0..=0x10FFFFu32).step_by(11) {
+        let name = v.char_name().unwrap();
+        let rendered = name.to_string();
+        let expected: Vec<_> = rendered
+            .split([' ', '-', '<', '>'])
+            .filter(|x| !x.is_empty())
+            .collect();
+        assert_eq!(name.words().collect::<Vec<_>>(), expected);
+    }
+}
+
+#[test]
+fn test_name_into_iter() {
+    fn join<'a>(words: impl IntoIterator<Item = &'a str>) -> String {
+        words.into_iter().collect::<Vec<_>>().join(" ")
+    }
+    let name = 0x4E00u32.char_name().unwrap();
+    assert_eq!(join(&name), "CJK UNIFIED IDEOGRAPH 4E00");
+    let name = 'a'.char_name().unwrap();
+    assert_eq!(join(&name), "LATIN SMALL LETTER A");
+}
+
+#[test]
+fn test_name_words_len() {
+    for &v in &[
+        0x41u32, 0x1180, 0x0F60, 0xFBF9, 0xFA0E, 0xD4DB, 0x4E00, 0x0081, 0xE000,
+    ] {
+        let name = v.char_name().unwrap();
+        let mut words = name.words();
+        let count = words.clone().count();
+        assert_eq!(words.len(), count);
+        assert_eq!(words.size_hint(), (count, Some(count)));
+        words.next();
+        assert_eq!(words.len(), count - 1);
+        assert_eq!(words.collect::<Vec<_>>().len(), count - 1);
+    }
+    for v in (0..=0x10FFFFu32).step_by(13) {
+        let name = v.char_name().unwrap();
+        assert_eq!(name.words().len(), name.words().count());
+    }
+}
+
+#[test]
+fn test_name_word_count() {
+    let word_count = |v: u32| v.char_name().unwrap().word_count();
+    assert_eq!(word_count(0x1180), 4);
+    assert_eq!(word_count(0xFBF9), 13);
+    assert_eq!(word_count(0x1F402), 1);
+    assert_eq!(word_count(0x0F60), 3);
+    assert_eq!(word_count(0x4E00), 4);
+    assert_eq!(word_count(0xAC00), 3);
+    assert_eq!(word_count(0x0081), 2);
+    assert_eq!(word_count(0xE000), 3);
+    for v in (0..=0x10FFFFu32).step_by(11) {
+        let name = v.char_name().unwrap();
+        assert_eq!(name.word_count(), name.words().count(), "U+{:04X}", v);
+    }
+}
+
+#[test]
+fn test_name_nth_word() {
+    let o_e = 0x1180u32.char_name().unwrap();
+    assert_eq!(o_e.nth_word(0), Some("HANGUL"));
+    assert_eq!(o_e.nth_word(2), Some("O"));
+    assert_eq!(o_e.nth_word(3), Some("E"));
+    assert_eq!(o_e.nth_word(4), None);
+    assert_eq!(o_e.last_word(), Some("E"));
+
+    let cjk = 0x4E00u32.char_name().unwrap();
+    assert_eq!(cjk.nth_word(2), Some("IDEOGRAPH"));
+    assert_eq!(cjk.nth_word(3), Some("4E00"));
+    assert_eq!(cjk.last_word(), Some("4E00"));
+    let compat = 0xF900u32.char_name().unwrap();
+    assert_eq!(compat.nth_word(3), Some("F900"));
+    assert_eq!(compat.last_word(), Some("F900"));
+
+    let minus_a = 0x0F60u32.char_name().unwrap();
+    assert_eq!(minus_a.nth_word(1), Some("LETTER"));
+    assert_eq!(minus_a.nth_word(2), Some("A"));
+    assert_eq!(0x0081u32.char_name().unwrap().nth_word(0), Some("control"));
+    assert_eq!(0x0081u32.char_name().unwrap().last_word(), Some("0081"));
+    assert_eq!(0x1F402u32.char_name().unwrap().last_word(), Some("OX"));
+    assert_eq!(0xAC00u32.char_name().unwrap().last_word(), Some("GA"));
+    assert_eq!(unicode_charname::Name::EMPTY.last_word(), None);
+
+    for v in (0..=0x10FFFFu32).step_by(97) {
+        let name = v.char_name().unwrap();
+        let words = name.words().collect::<Vec<_>>();
+        for n in 0..=words.len() {
+            assert_eq!(name.nth_word(n), words.get(n).copied(), "U+{:04X}", v);
+        }
+        assert_eq!(name.last_word(), words.last().copied(), "U+{:04X}", v);
+    }
+}
+
+#[test]
+fn test_name_words_rev() {
+    let name = 0xFBF9u32.char_name().unwrap();
+    let mut words = name.words();
+    assert_eq!(words.next_back(), Some("FORM"));
+    assert_eq!(words.next_back(), Some("ISOLATED"));
+    assert_eq!(words.next(), Some("ARABIC"));
+    assert_eq!(words.len(), 10);
+
+    let name = 0x1180u32.char_name().unwrap();
+    let mut words = name.words();
+    assert_eq!(words.next_back(), Some("E"));
+    assert_eq!(words.next_back(), Some("O"));
+    assert_eq!(words.next(), Some("HANGUL"));
+    assert_eq!(words.next(), Some("JUNGSEONG"));
+    assert_eq!(words.next(), None);
+    assert_eq!(words.next_back(), None);
+
+    assert_eq!(
+        0x4E00u32.char_name().unwrap().words().next_back(),
+        Some("4E00")
+    );
+    assert_eq!(
+        0xFA0Eu32.char_name().unwrap().words().next_back(),
+        Some("FA0E")
+    );
+
+    for v in (0..=0x10FFFFu32).step_by(17).chain(0x1160..0x11A8) {
+        let name = v.char_name().unwrap();
+        let mut forward: Vec<_> = name.words().collect();
+        forward.reverse();
+        assert_eq!(name.words().rev().collect::<Vec<_>>(), forward);
+    }
+}
+
+#[test]
+fn test_name_to_cow() {
+    use std::borrow::Cow;
+
+    let ox = 0x1F402u32.char_name().unwrap().to_cow();
+    assert_eq!(ox, "OX");
+    assert!(matches!(ox, Cow::Borrowed(_)));
+    // borrowed names point into the same static table entry.
+    let ox_again = 0x1F402u32.char_name().unwrap().to_cow();
+    assert_eq!(ox.as_ptr(), ox_again.as_ptr());
+
+    let a = 'A'.char_name().unwrap().to_cow();
+    assert_eq!(a, "LATIN CAPITAL LETTER A");
+    assert!(matches!(a, Cow::Owned(_)));
+
+    let hangul = 0xAC00u32.char_name().unwrap().to_cow();
+    assert_eq!(hangul, "HANGUL SYLLABLE GA");
+    assert!(matches!(hangul, Cow::Owned(_)));
+
+    for v in (0..=0x10FFFFu32).step_by(19) {
+        let name = v.char_name().unwrap();
+        assert_eq!(name.to_cow(), name.to_string());
+    }
+}
+
+#[test]
+fn test_name_as_static_str() {
+    assert_eq!(0x1F402u32.char_name().unwrap().as_static_str(), Some("OX"));
+    assert_eq!(
+        0x1F514u32.char_name().unwrap().as_static_str(),
+        Some("BELL")
+    );
+    assert_eq!('A'.char_name().unwrap().as_static_str(), None);
+    assert_eq!(0xAC00u32.char_name().unwrap().as_static_str(), None);
+    assert_eq!(0x4E00u32.char_name().unwrap().as_static_str(), None);
+    assert_eq!(0x0081u32.char_name().unwrap().as_static_str(), None);
+
+    for v in (0..=0x10FFFFu32).step_by(7) {
+        let name = v.char_name().unwrap();
+        if let Some(s) = name.as_static_str() {
+            assert_eq!(name.to_string(), s);
+            assert!(!s.contains(' ') && !s.contains('-'));
+        }
+    }
+}
+
+#[test]
+fn test_name_into_string() {
+    use std::borrow::Cow;
+
+    assert_eq!(
+        'A'.char_name().unwrap().into_string(),
+        "LATIN CAPITAL LETTER A"
+    );
+    assert_eq!(
+        String::from(0xAC00u32.char_name().unwrap()),
+        "HANGUL SYLLABLE GA"
+    );
+    let ox: Cow<'static, str> = 0x1F402u32.char_name().unwrap().into();
+    assert!(matches!(ox, Cow::Borrowed("OX")));
+    let a: Cow<'static, str> = 'A'.char_name().unwrap().into();
+    assert_eq!(a, "LATIN CAPITAL LETTER A");
+    for v in (0..=0x10FFFFu32).step_by(23) {
+        let name = v.char_name().unwrap();
+        assert_eq!(name.to_string(), name.clone().into_string());
+    }
+}
+
+#[test]
+fn test_name_display_flags() {
+    let a = 'A'.char_name().unwrap();
+    let hangul = 0xAC00u32.char_name().unwrap();
+    assert_eq!(format!("{:<25}|", a), "LATIN CAPITAL LETTER A   |");
+    assert_eq!(format!("{:25}|", a), "LATIN CAPITAL LETTER A   |");
+    assert_eq!(format!("{:>25}|", a), "   LATIN CAPITAL LETTER A|");
+    assert_eq!(format!("{:^26}|", a), "  LATIN CAPITAL LETTER A  |");
+    assert_eq!(format!("{:*^25}|", a), "*LATIN CAPITAL LETTER A**|");
+    assert_eq!(format!("{:-<20}|", hangul), "HANGUL SYLLABLE GA--|");
+    assert_eq!(format!("{:5}|", a), "LATIN CAPITAL LETTER A|");
+    assert_eq!(format!("{:.10}|", a), "LATIN CAPI|");
+    assert_eq!(format!("{:.6}|", a), "LATIN |");
+    assert_eq!(format!("{:.10}|", hangul), "HANGUL SYL|");
+    assert_eq!(format!("{:.100}|", hangul), "HANGUL SYLLABLE GA|");
+    assert_eq!(format!("{:>12.10}|", a), "  LATIN CAPI|");
+    assert_eq!(format!("{:.0}|", a), "|");
+    for v in (0..=0x10FFFFu32).step_by(29) {
+        let name = v.char_name().unwrap();
+        let s = name.to_string();
+        assert_eq!(format!("{:>90}", name), format!("{:>90}", s));
+        assert_eq!(format!("{:.7}", name), format!("{:.7}", s));
+    }
+}
+
+#[test]
+fn test_name_display_alternate() {
+    let name = |v: u32| v.char_name().unwrap();
+    assert_eq!(format!("{:#}", name(0x41)), "U+0041 LATIN CAPITAL LETTER A");
+    assert_eq!(format!("{:#}", name(0x1F402)), "U+1F402 OX");
+    assert_eq!(
+        format!("{:#}", name(0x10FFFF)),
+        "U+10FFFF <noncharacter-10FFFF>"
+    );
+    assert_eq!(format!("{:#}", name(0x0378)), "U+0378 <reserved-0378>");
+    assert_eq!(format!("{:#}", name(0x0081)), "U+0081 <control-0081>");
+    assert_eq!(format!("{:#}", name(0xAC00)), "U+AC00 HANGUL SYLLABLE GA");
+    assert_eq!(
+        format!("{:#}", name(0x20000)),
+        "U+20000 CJK UNIFIED IDEOGRAPH-20000"
+    );
+    assert_eq!(
+        format!("{:#}", name(0xF900)),
+        "U+F900 CJK COMPATIBILITY IDEOGRAPH-F900"
+    );
+    assert_eq!(format!("{:>#12}|", name(0x1F402)), "  U+1F402 OX|");
+    assert_eq!(format!("{:#.8}|", name(0x41)), "U+0041 L|");
+    assert_eq!(format!("{:#.4}|", name(0x41)), "U+00|");
+    // the flag doesn't leak into the debug output.
+    assert_eq!(format!("{:#?}", name(0x1F402)), "Name(\n    \"OX\",\n)");
+}
+
+#[test]
+fn test_name_escape() {
+    let escape = |v: u32| v.char_name().unwrap().escape().to_string();
+    assert_eq!(escape(0x61), r"\N{LATIN SMALL LETTER A}");
+    assert_eq!(escape(0x1180), r"\N{HANGUL JUNGSEONG O-E}");
+    assert_eq!(escape(0x4E00), r"\N{CJK UNIFIED IDEOGRAPH-4E00}");
+    assert_eq!(escape(0xAC00), r"\N{HANGUL SYLLABLE GA}");
+    assert_eq!(escape(0x0081), r"\u{0081}");
+    assert_eq!(escape(0x0009), r"\u{0009}");
+    assert_eq!(escape(0xE000), r"\u{E000}");
+    assert_eq!(escape(0x10FFFF), r"\u{10FFFF}");
+
+    assert_eq!(
+        'é'.name_escape().unwrap(),
+        r"\N{LATIN SMALL LETTER E WITH ACUTE}"
+    );
+    assert_eq!('\n'.name_escape().unwrap(), r"\u{000A}");
+    assert_eq!(0x110000u32.name_escape(), None);
+}
+
+struct CappedWriter {
+    buf: [u8; 16],
+    len: usize,
+}
+
+impl std::fmt::Write for CappedWriter {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(std::fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_name_write_to() {
+    let mut w = CappedWriter {
+        buf: [0; 16],
+        len: 0,
+    };
+    assert!(0x1F402u32.char_name().unwrap().write_to(&mut w).is_ok());
+    assert_eq!(&w.buf[..w.len], b"OX");
+
+    let mut w = CappedWriter {
+        buf: [0; 16],
+        len: 0,
+    };
+    assert!('A'.char_name().unwrap().write_to(&mut w).is_err());
+    assert_eq!(&w.buf[..w.len], b"LATIN CAPITAL ");
+
+    for v in (0..=0x10FFFFu32).step_by(31) {
+        let name = v.char_name().unwrap();
+        let mut s = String::new();
+        name.write_to(&mut s).unwrap();
+        assert_eq!(s, name.to_string());
+    }
+}
+
+#[test]
+fn test_name_chars() {
+    assert!('A'
+        .char_name()
+        .unwrap()
+        .chars()
+        .eq("LATIN CAPITAL LETTER A".chars()));
+    for v in (0..=0x10FFFFu32).step_by(7) {
+        let name = v.char_name().unwrap();
+        assert!(name.chars().eq(name.to_string().chars()), "U+{:04X}", v);
+    }
+}
+
+#[test]
+fn test_name_parts() {
+    let check = |v: u32, expected: &[Part]| {
+        assert!(v.char_name().unwrap().parts().eq(expected.iter().copied()));
+    };
+    check(
+        0x4E00,
+        &[
+            Part::Word("CJK"),
+            Part::Space,
+            Part::Word("UNIFIED"),
+            Part::Space,
+            Part::Word("IDEOGRAPH"),
+            Part::Hyphen,
+            Part::CodePointHex("4E00"),
+        ],
+    );
+    check(
+        0xF900,
+        &[
+            Part::Word("CJK"),
+            Part::Space,
+            Part::Word("COMPATIBILITY"),
+            Part::Space,
+            Part::Word("IDEOGRAPH"),
+            Part::Hyphen,
+            Part::CodePointHex("F900"),
+        ],
+    );
+    check(
+        0x0081,
+        &[
+            Part::OpenBracket,
+            Part::Word("control"),
+            Part::Hyphen,
+            Part::CodePointHex("0081"),
+            Part::CloseBracket,
+        ],
+    );
+    check(
+        0xE000,
+        &[
+            Part::OpenBracket,
+            Part::Word("private"),
+            Part::Hyphen,
+            Part::Word("use"),
+            Part::Hyphen,
+            Part::CodePointHex("E000"),
+            Part::CloseBracket,
+        ],
+    );
+    check(
+        0x1180,
+        &[
+            Part::Word("HANGUL"),
+            Part::Space,
+            Part::Word("JUNGSEONG"),
+            Part::Space,
+            Part::Word("O"),
+            Part::Hyphen,
+            Part::Word("E"),
+        ],
+    );
+    check(
+        0xAC00,
+        &[
+            Part::Word("HANGUL"),
+            Part::Space,
+            Part::Word("SYLLABLE"),
+            Part::Space,
+            Part::Word("GA"),
+        ],
+    );
+    assert_eq!(
+        0x4E00u32.property_name().unwrap().parts().last(),
+        Some(Part::CodePointHex("4E00"))
+    );
+
+    for v in (0..=0x10FFFFu32).step_by(3) {
+        let name = v.char_name().unwrap();
+        let parts = name.parts().map(|part| part.as_str()).collect::<String>();
+        assert_eq!(parts, name.to_string(), "U+{:04X}", v);
+        assert!(
+            name.parts()
+                .filter_map(|part| match part {
+                    Part::Word(s) | Part::CodePointHex(s) => Some(s),
+                    _ => None,
+                })
+                .eq(name.words()),
+            "U+{:04X}",
+            v
+        );
+    }
+}
+
+#[test]
+fn test_name_bytes() {
+    assert!('A'
+        .char_name()
+        .unwrap()
+        .bytes()
+        .eq(b"LATIN CAPITAL LETTER A".iter().copied()));
+    for v in (0..=0x1FFFFu32).step_by(5) {
+        let name = v.char_name().unwrap();
+        assert_eq!(
+            name.bytes().collect::<Vec<u8>>(),
+            name.to_string().into_bytes(),
+            "U+{:04X}",
+            v
+        );
+    }
+    let mut buf = Vec::new();
+    for v in 0..=0x10FFFFu32 {
+        let name = v.char_name().unwrap();
+        buf.clear();
+        buf.extend(name.bytes());
+        assert_eq!(buf.len(), name.len(), "U+{:04X}", v);
+        assert!(buf.is_ascii(), "U+{:04X}", v);
+    }
+}
+
+#[test]
+fn test_name_contains_word() {
+    let arrow = 0x2190u32.char_name().unwrap();
+    assert_eq!(arrow.to_string(), "LEFTWARDS ARROW");
+    assert!(arrow.contains_word("ARROW"));
+    assert!(arrow.contains_word("LEFTWARDS"));
+    assert!(!arrow.contains_word("LEFTWARDS ARROW"));
+    assert!(!arrow.contains_word("RROW"));
+    assert!(!arrow.contains_word("arrow"));
+    assert!(!arrow.contains_word(""));
+
+    let arrowhead = 0x2B9Cu32.char_name().unwrap();
+    assert_eq!(
+        arrowhead.to_string(),
+        "BLACK LEFTWARDS EQUILATERAL ARROWHEAD"
+    );
+    assert!(!arrowhead.contains_word("ARROW"));
+    assert!(arrowhead.contains_word("ARROWHEAD"));
+    assert!(!0x2192u32.char_name().unwrap().contains_word("NARROW"));
+
+    let oe = 0x1180u32.char_name().unwrap();
+    assert!(oe.contains_word("O"));
+    assert!(oe.contains_word("E"));
+    assert!(!oe.contains_word("O-E"));
+    assert!(!oe.contains_word("-"));
+    assert!(!oe.contains_word("@"));
+
+    let compat = 0xFA0Eu32.char_name().unwrap();
+    assert!(compat.contains_word("FA0E"));
+    assert!(compat.contains_word("IDEOGRAPH"));
+    assert!(!compat.contains_word("FA0F"));
+    assert!(!'A'.char_name().unwrap().contains_word("0041"));
+
+    let cjk = 0x4E00u32.char_name().unwrap();
+    assert!(cjk.contains_word("4E00"));
+    assert!(cjk.contains_word("UNIFIED"));
+    assert!(!cjk.contains_word("IDEOGRAPH-4E00"));
+    assert!(0x0081u32.char_name().unwrap().contains_word("control"));
+    assert!(!0x0081u32.char_name().unwrap().contains_word("<control"));
+
+    for v in (0..=0x10FFFFu32).step_by(37) {
+        let name = v.char_name().unwrap();
+        for word in name.words() {
+            assert!(name.contains_word(word));
+        }
+    }
+}
+
+#[test]
+fn test_name_starts_ends_with() {
+    let alpha = 'α'.char_name().unwrap();
+    assert!(alpha.starts_with("GREEK SMALL LETTER"));
+    assert!(alpha.starts_with("GREEK SMALL LETTER "));
+    assert!(alpha.starts_with("GREEK SMA"));
+    assert!(alpha.starts_with(""));
+    assert!(alpha.starts_with("GREEK SMALL LETTER ALPHA"));
+    assert!(!alpha.starts_with("GREEK SMALL LETTER ALPHAS"));
+    assert!(!alpha.starts_with("GREEK  SMALL"));
+    assert!(!alpha.starts_with("GREEK CAPITAL"));
+    assert!(alpha.ends_with("LETTER ALPHA"));
+    assert!(alpha.ends_with(" ALPHA"));
+    assert!(alpha.ends_with("HA"));
+    assert!(alpha.ends_with(""));
+    assert!(alpha.ends_with("GREEK SMALL LETTER ALPHA"));
+    assert!(!alpha.ends_with(" GREEK SMALL LETTER ALPHA"));
+    assert!(!alpha.ends_with("BETA"));
+
+    let cjk = 0x4E00u32.char_name().unwrap();
+    assert!(cjk.starts_with("CJK UNIFIED IDEOGRAPH-4E"));
+    assert!(!cjk.starts_with("CJK UNIFIED IDEOGRAPH-4F"));
+    assert!(cjk.ends_with("-4E00"));
+    let compat = 0xFA0Eu32.char_name().unwrap();
+    assert!(compat.starts_with("CJK COMPATIBILITY IDEOGRAPH-FA"));
+    assert!(compat.ends_with("IDEOGRAPH-FA0E"));
+    assert!(!compat.ends_with("IDEOGRAPH-FA0F"));
+    assert!(0x0081u32.char_name().unwrap().starts_with("<control-"));
+    assert!(0x0081u32.char_name().unwrap().ends_with("0081>"));
+}
+
+#[test]
+fn test_name_find() {
+    let a = 'A'.char_name().unwrap();
+    assert_eq!(a.find("CAPITAL LETTER"), Some(6));
+    assert_eq!(a.find("LATIN CAPITAL LETTER A"), Some(0));
+    assert_eq!(a.find("LATIN CAPITAL LETTER A "), None);
+    assert_eq!(a.find("N C"), Some(4));
+    assert_eq!(a.find("A"), Some(1));
+    assert_eq!(a.find(" A"), Some(20));
+    assert_eq!(a.find(""), Some(0));
+    assert_eq!(a.find("capital"), None);
+    assert!(a.contains("TAL LET"));
+    assert!(!a.contains("LETTERS"));
+
+    let cjk = 0x4E00u32.char_name().unwrap();
+    assert_eq!(cjk.find("PH-4E"), Some(19));
+    assert_eq!(cjk.find("4E00"), Some(22));
+    assert_eq!(cjk.find("4E01"), None);
+    let compat = 0xF900u32.char_name().unwrap();
+    assert_eq!(compat.find("H-F9"), Some(26));
+    assert_eq!(compat.find("F900"), Some(28));
+    assert_eq!(0x0081u32.char_name().unwrap().find("0081>"), Some(9));
+
+    for v in (0..=0x10FFFFu32).step_by(101) {
+        let name = v.char_name().unwrap();
+        let s = name.to_string();
+        for needle in &[
+            &s[..],
+            &s[1..],
+            &s[..s.len() / 2],
+            &s[s.len() / 3..s.len() / 2],
+        ] {
+            assert_eq!(name.find(needle), s.find(needle), "U+{:04X}", v);
+        }
+    }
+}
+
+#[test]
+fn test_name_wrapped() {
+    let lines = |v: u32, width: usize| {
+        v.char_name()
+            .unwrap()
+            .wrapped(width)
+            .map(|line| {
+                let s = line.to_string();
+                assert_eq!(line.len(), s.len());
+                s
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        lines(0xFBF9, 10),
+        [
+            "ARABIC",
+            "LIGATURE",
+            "UIGHUR",
+            "KIRGHIZ",
+            "YEH WITH",
+            "HAMZA",
+            "ABOVE WITH",
+            "ALEF",
+            "MAKSURA",
+            "ISOLATED",
+            "FORM"
+        ]
+    );
+    assert_eq!(
+        lines(0xFBF9, 20),
+        [
+            "ARABIC LIGATURE",
+            "UIGHUR KIRGHIZ YEH",
+            "WITH HAMZA ABOVE",
+            "WITH ALEF MAKSURA",
+            "ISOLATED FORM"
+        ]
+    );
+    assert_eq!(
+        lines(0xFBF9, 80),
+        [
+            "ARABIC LIGATURE UIGHUR KIRGHIZ YEH WITH HAMZA ABOVE WITH ALEF MAKSURA ISOLATED",
+            "FORM"
+        ]
+    );
+    assert_eq!(
+        lines(0x1FBA8, 20),
+        [
+            "BOX DRAWINGS LIGHT",
+            "DIAGONAL UPPER",
+            "CENTRE TO MIDDLE",
+            "LEFT AND MIDDLE",
+            "RIGHT TO LOWER",
+            "CENTRE"
+        ]
+    );
+    assert_eq!(
+        lines(0x1FBA8, 80),
+        [
+            "BOX DRAWINGS LIGHT DIAGONAL UPPER CENTRE TO MIDDLE LEFT AND MIDDLE RIGHT TO",
+            "LOWER CENTRE"
+        ]
+    );
+    assert_eq!(
+        lines(0x1D63C, 10),
+        ["MATHEMATICAL", "SANS-SERIF", "BOLD", "ITALIC", "CAPITAL A"]
+    );
+    assert_eq!(
+        lines(0x1D63C, 20),
+        ["MATHEMATICAL", "SANS-SERIF BOLD", "ITALIC CAPITAL A"]
+    );
+    assert_eq!(
+        lines(0x1D63C, 80),
+        ["MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL A"]
+    );
+    assert_eq!(lines(0x20000, 10), ["CJK", "UNIFIED", "IDEOGRAPH-20000"]);
+    assert_eq!(lines(0x20000, 20), ["CJK UNIFIED", "IDEOGRAPH-20000"]);
+    assert_eq!(lines(0x20000, 80), ["CJK UNIFIED IDEOGRAPH-20000"]);
+    assert_eq!(lines(0x0081, 5), ["<control-0081>"]);
+    assert_eq!(lines(0x1180, 0), ["HANGUL", "JUNGSEONG", "O-E"]);
+    assert_eq!(lines(0x1F402, 80), ["OX"]);
+    assert_eq!(unicode_charname::Name::EMPTY.wrapped(80).count(), 0);
+}
+
+#[test]
+fn test_name_strip_prefix() {
+    let strip = |v: u32, prefix: &str| {
+        v.char_name()
+            .unwrap()
+            .strip_prefix(prefix)
+            .map(|rest| rest.to_string())
+    };
+    assert_eq!(strip(0x3B1, "GREEK SMALL LETTER"), some_s("ALPHA"));
+    assert_eq!(strip(0x3B1, "GREEK SMALL LETTER "), some_s("ALPHA"));
+    assert_eq!(strip(0x3B2, "GREEK SMALL"), some_s("LETTER BETA"));
+    assert_eq!(strip(0x3B1, "GREEK SMALL LET"), some_s("TER ALPHA"));
+    assert_eq!(strip(0x3B1, "GREEK SMALL LETTER AL"), some_s("PHA"));
+    assert_eq!(strip(0x3B1, "GREEK SMALL LETTER ALPHA"), some_s(""));
+    assert_eq!(strip(0x3B1, ""), some_s("GREEK SMALL LETTER ALPHA"));
+    assert_eq!(strip(0x3B1, "GREEK CAPITAL LETTER"), None);
+    assert_eq!(strip(0x3B1, "GREEK SMALL LETTER ALPHAS"), None);
+    assert_eq!(strip(0x3B1, "greek small letter"), None);
+    assert_eq!(strip(0x1180, "HANGUL JUNGSEONG O"), some_s("-E"));
+    assert_eq!(strip(0x4E00, "CJK UNIFIED IDEOGRAPH-"), some_s("4E00"));
+    assert_eq!(strip(0x4E00, "CJK UNIFIED IDEOGRAPH-4E"), some_s("00"));
+    assert_eq!(
+        strip(0xF900, "CJK COMPATIBILITY IDEOGRAPH-F"),
+        some_s("900")
+    );
+    assert_eq!(strip(0xAC01, "HANGUL SYLLABLE"), some_s("GAG"));
+    assert_eq!(strip(0x0081, "<control-"), some_s("0081>"));
+}
+
+#[test]
+fn test_name_display_lowercase() {
+    fn lower(v: u32) -> String {
+        v.char_name().unwrap().display_lowercase().to_string()
+    }
+    assert_eq!(lower(0xE9), "latin small letter e with acute");
+    assert_eq!(lower(0x1180), "hangul jungseong o-e");
+    assert_eq!(lower(0x0081), "<control-0081>");
+    assert_eq!(lower(0x1029F), "<reserved-1029f>");
+    assert_eq!(lower(0xD4DB), "hangul syllable pwilh");
+    assert_eq!(lower(0x4E00), "cjk unified ideograph-4e00");
+    assert_eq!(lower(0xFA0E), "cjk compatibility ideograph-fa0e");
+    for v in (0..=0x10FFFFu32).step_by(41) {
+        assert_eq!(
+            lower(v),
+            v.char_name().unwrap().to_string().to_ascii_lowercase()
+        );
+    }
+}
+
+#[test]
+fn test_name_display_titlecase() {
+    fn title(v: u32) -> String {
+        v.char_name().unwrap().display_titlecase().to_string()
+    }
+    assert_eq!(title(0x41), "Latin Capital Letter A");
+    assert_eq!(title(0xE9), "Latin Small Letter E With Acute");
+    assert_eq!(title(0x1180), "Hangul Jungseong O-E");
+    assert_eq!(title(0x10000), "Linear B Syllable B008 A");
+    assert_eq!(title(0x4E00), "Cjk Unified Ideograph-4E00");
+    assert_eq!(title(0xFA0E), "Cjk Compatibility Ideograph-FA0E");
+    assert_eq!(title(0x1F600), "Grinning Face");
+    assert_eq!(title(0xD4DB), "Hangul Syllable Pwilh");
+    assert_eq!(title(0x0081), "<Control-0081>");
+    assert_eq!(title(0xE000), "<Private-Use-E000>");
+    for v in (0..=0x10FFFFu32).step_by(43) {
+        let name = v.char_name().unwrap().to_string();
+        assert!(title(v).eq_ignore_ascii_case(&name));
+    }
+}
+
+#[test]
+fn test_name_to_identifier() {
+    fn ident(v: u32) -> String {
+        v.char_name().unwrap().to_identifier()
+    }
+    assert_eq!(ident(0x2219), "BULLET_OPERATOR");
+    assert_eq!(ident(0x31), "DIGIT_ONE");
+    assert_eq!(
+        ident(0xFBF9),
+        "ARABIC_LIGATURE_UIGHUR_KIRGHIZ_YEH_WITH_HAMZA_ABOVE_WITH_ALEF_MAKSURA_ISOLATED_FORM"
+    );
+    assert_eq!(ident(0x1180), "HANGUL_JUNGSEONG_O_E");
+    assert_eq!(ident(0x116C), "HANGUL_JUNGSEONG_OE");
+    assert_eq!(ident(0x4E00), "CJK_UNIFIED_IDEOGRAPH_4E00");
+    assert_eq!(ident(0x17000), "TANGUT_IDEOGRAPH_17000");
+    assert_eq!(ident(0xAC00), "HANGUL_SYLLABLE_GA");
+    assert_eq!(ident(0x0081), "CONTROL_0081");
+    assert_eq!(ident(0xE000), "PRIVATE_USE_E000");
+
+    let mut seen = std::collections::HashSet::new();
+    for v in 0..=0x10FFFFu32 {
+        if let Some(name) = v.property_name() {
+            let identifier = name.to_identifier();
+            assert!(identifier
+                .bytes()
+                .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'_'));
+            assert!(!identifier.starts_with(|c: char| c.is_ascii_digit()));
+            assert!(seen.insert(identifier), "U+{:04X}", v);
+        }
+    }
+}
+
+#[test]
+fn test_name_to_slug() {
+    fn slug(v: u32) -> String {
+        v.char_name().unwrap().to_slug()
+    }
+    assert_eq!(slug(0xE9), "latin-small-letter-e-with-acute");
+    assert_eq!(slug(0x1180), "hangul-jungseong-o-e");
+    assert_eq!(slug(0x4E00), "cjk-unified-ideograph-4e00");
+    assert_eq!(slug(0xAC00), "hangul-syllable-ga");
+    assert_eq!(slug(0x0081), "control-0081");
+    assert_eq!(slug(0x1029F), "reserved-1029f");
+    for v in (0..=0x10FFFFu32).step_by(47) {
+        assert!(slug(v)
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-'));
+    }
+}
+
+#[test]
+fn test_name_to_camel_case() {
+    fn camel(v: u32) -> String {
+        v.char_name().unwrap().to_camel_case()
+    }
+    assert_eq!(camel(0x41), "LatinCapitalLetterA");
+    assert_eq!(
+        camel(0xFBF9),
+        "ArabicLigatureUighurKirghizYehWithHamzaAboveWithAlefMaksuraIsolatedForm"
+    );
+    assert_eq!(camel(0x1180), "HangulJungseongOE");
+    assert_eq!(camel(0x116C), "HangulJungseongOe");
+    assert_eq!(camel(0x4E00), "CjkUnifiedIdeograph4E00");
+    assert_eq!(camel(0xFA0E), "CjkCompatibilityIdeographFA0E");
+    assert_eq!(camel(0x10000), "LinearBSyllableB008A");
+    assert_eq!(camel(0xAC01), "HangulSyllableGag");
+    assert_eq!(camel(0x0081), "Control0081");
+
+    let mut seen = std::collections::HashMap::new();
+    let mut collisions = Vec::new();
+    for v in 0..=0x10FFFFu32 {
+        if let Some(name) = v.property_name() {
+            let identifier = name.to_camel_case();
+            assert!(identifier.bytes().all(|b| b.is_ascii_alphanumeric()));
+            if let Some(prev) = seen.insert(identifier, v) {
+                collisions.push((prev, v));
+            }
+        }
+    }
+    assert_eq!(
+        collisions,
+        [
+            (0x0F60, 0x0F68),
+            (0x0FB0, 0x0FB8),
+            (0x11A00, 0x11A29),
+            (0x11A50, 0x11A7A),
+            (0x11C88, 0x11C8F),
+        ]
+    );
+}
+
+#[test]
+fn test_matches_loose() {
+    let zwj = 0x200Du32.char_name().unwrap();
+    for candidate in &[
+        "ZERO WIDTH JOINER",
+        "zero width joiner",
+        "ZERO-WIDTH JOINER",
+        "zero_width_joiner",
+        "ZeroWidthJoiner",
+        " zero width joiner\t",
+    ] {
+        assert!(zwj.matches_loose(candidate), "{}", candidate);
+    }
+    assert!(!zwj.matches_loose("ZERO WIDTH NON-JOINER"));
+    assert!(!zwj.matches_loose("ZERO WIDTH JOINE"));
+    assert!(!zwj.matches_loose("ZERO WIDTH JOINERS"));
+    assert!(!zwj.matches_loose("ZERO -WIDTH JOINER"));
+
+    // Simple case folding: U+212A KELVIN SIGN folds to 'k', U+017F LATIN
+    // SMALL LETTER LONG S to 's'.
+    let k = 0x4Bu32.char_name().unwrap();
+    assert!(k.matches_loose("latin capital letter \u{212A}"));
+    assert!(!k.matches_loose("latin small letter \u{212A}"));
+    assert!(0x73u32
+        .char_name()
+        .unwrap()
+        .matches_loose("latin \u{017F}mall letter \u{017F}"));
+
+    // The hyphen of U+1180 HANGUL JUNGSEONG O-E is significant.
+    let o_e = 0x1180u32.char_name().unwrap();
+    let oe = 0x116Cu32.char_name().unwrap();
+    assert!(o_e.matches_loose("HANGUL JUNGSEONG O-E"));
+    assert!(o_e.matches_loose("hangul_jungseong_o-e"));
+    assert!(!o_e.matches_loose("HANGUL JUNGSEONG OE"));
+    assert!(!o_e.matches_loose("HANGUL JUNGSEONG O E"));
+    assert!(oe.matches_loose("HANGUL JUNGSEONG OE"));
+    assert!(oe.matches_loose("hangul jungseong o e"));
+    assert!(!oe.matches_loose("HANGUL JUNGSEONG O-E"));
+
+    // Non-medial hyphens are significant.
+    let a = 0x0F68u32.char_name().unwrap();
+    let minus_a = 0x0F60u32.char_name().unwrap();
+    assert!(minus_a.matches_loose("TIBETAN LETTER -A"));
+    assert!(minus_a.matches_loose("tibetan letter -a"));
+    assert!(!minus_a.matches_loose("TIBETAN LETTER A"));
+    assert!(!minus_a.matches_loose("TIBETAN LETTER-A"));
+    assert!(a.matches_loose("TIBETAN LETTER A"));
+    assert!(!a.matches_loose("TIBETAN LETTER -A"));
+
+    // Medial hyphens are ignored in generated names too.
+    let cjk = 0x4E00u32.char_name().unwrap();
+    assert!(cjk.matches_loose("cjk unified ideograph 4e00"));
+    assert!(cjk.matches_loose("CJK UNIFIED IDEOGRAPH4E00"));
+}
+
+#[test]
+fn test_name_code_point() {
+    use unicode_charname::Name;
+
+    for &c in &[
+        'A',
+        '\u{0}',
+        '\u{81}',
+        '\u{1180}',
+        '\u{AC00}',
+        '\u{4E00}',
+        '\u{E000}',
+        '\u{1F402}',
+    ] {
+        let name = c.char_name().unwrap();
+        assert_eq!(name.code_point(), Some(c as u32));
+        assert_eq!(name.to_char(), Some(c));
+    }
+    for &v in &[0xD800u32, 0xDBFF, 0xDC00, 0xDFFF] {
+        let name = v.char_name().unwrap();
+        assert_eq!(name.code_point(), Some(v));
+        assert_eq!(name.to_char(), None);
+    }
+    for &v in &[0x0378u32, 0xFDD0, 0x10FFFF, 0xE0080] {
+        let name = v.char_name().unwrap();
+        assert_eq!(name.code_point(), Some(v));
+        assert_eq!(name.to_char(), std::char::from_u32(v));
+    }
+    let name = 0x4E00u32.property_name().unwrap();
+    assert_eq!(name.code_point(), Some(0x4E00));
+    assert_eq!(Name::EMPTY.code_point(), None);
+    assert_eq!(Name::EMPTY.to_char(), None);
+    for v in (0..=0x10FFFFu32).step_by(37) {
+        assert_eq!(v.char_name().unwrap().code_point(), Some(v));
+    }
+}
+
+#[test]
+fn test_name_algorithmic_parts() {
+    let parts = |v: u32| v.char_name().unwrap().algorithmic_parts();
+    assert_eq!(parts(0x41), None);
+    assert_eq!(parts(0xAC00), None);
+    assert_eq!(parts(0xF900), None);
+    assert_eq!(parts(0x0081), Some(("control-", 0x0081)));
+    assert_eq!(parts(0x3400), Some(("CJK UNIFIED IDEOGRAPH-", 0x3400)));
+    assert_eq!(parts(0x4E00), Some(("CJK UNIFIED IDEOGRAPH-", 0x4E00)));
+    assert_eq!(parts(0x20000), Some(("CJK UNIFIED IDEOGRAPH-", 0x20000)));
+    assert_eq!(parts(0x2A700), Some(("CJK UNIFIED IDEOGRAPH-", 0x2A700)));
+    assert_eq!(parts(0x2B740), Some(("CJK UNIFIED IDEOGRAPH-", 0x2B740)));
+    assert_eq!(parts(0x2B820), Some(("CJK UNIFIED IDEOGRAPH-", 0x2B820)));
+    assert_eq!(parts(0x2CEB0), Some(("CJK UNIFIED IDEOGRAPH-", 0x2CEB0)));
+    assert_eq!(parts(0x30000), Some(("CJK UNIFIED IDEOGRAPH-", 0x30000)));
+    assert_eq!(parts(0x17000), Some(("TANGUT IDEOGRAPH-", 0x17000)));
+    assert_eq!(parts(0x18D00), Some(("TANGUT IDEOGRAPH-", 0x18D00)));
+    assert_eq!(parts(0xD800), Some(("surrogate-", 0xD800)));
+    assert_eq!(parts(0xDB80), Some(("surrogate-", 0xDB80)));
+    assert_eq!(parts(0xDC00), Some(("surrogate-", 0xDC00)));
+    assert_eq!(parts(0xE000), Some(("private-use-", 0xE000)));
+    assert_eq!(parts(0xF0000), Some(("private-use-", 0xF0000)));
+    assert_eq!(parts(0x100000), Some(("private-use-", 0x100000)));
+    assert_eq!(parts(0xFDD0), Some(("noncharacter-", 0xFDD0)));
+    assert_eq!(parts(0x0378), Some(("reserved-", 0x0378)));
+    assert_eq!(
+        0x4E00u32.property_name().unwrap().algorithmic_parts(),
+        Some(("CJK UNIFIED IDEOGRAPH-", 0x4E00))
+    );
+}
+
+#[test]
+fn test_name_custom() {
+    use unicode_charname::Name;
+
+    for &v in &[0x41u32, 0x1180, 0xFBF9, 0xAC00, 0x4E00, 0xF900, 0x1F402] {
+        let name = v.char_name().unwrap();
+        let custom = Name::custom(name.to_string()).unwrap();
+        assert_eq!(custom, name);
+        assert_eq!(custom.to_string(), name.to_string());
+        assert!(custom.words().eq(name.words()));
+        assert_eq!(custom.code_point(), None);
+        assert_eq!(custom.kind(), NameKind::Explicit);
+    }
+    assert!(Name::custom("TIBETAN LETTER -A").is_ok());
+    let custom = Name::custom("PRIVATE CAT FACE-2").unwrap();
+    assert_eq!(custom.to_string(), "PRIVATE CAT FACE-2");
+    assert_eq!(format!("{:#}", custom), "PRIVATE CAT FACE-2");
+    assert_eq!(custom.word_count(), 4);
+
+    let offset = |s: &str| Name::custom(s).unwrap_err().offset();
+    assert_eq!(offset(""), 0);
+    assert_eq!(offset("latin small letter a"), 0);
+    assert_eq!(offset("LATIN small"), 6);
+    assert_eq!(offset("<control-0081>"), 0);
+    assert_eq!(offset("control-0081"), 0);
+    assert_eq!(offset("-A"), 0);
+    assert_eq!(offset("A-"), 1);
+    assert_eq!(offset("A  B"), 2);
+    assert_eq!(offset("A 1"), 2);
+    assert_eq!(offset("1A"), 0);
+    assert_eq!(offset("A\u{e9}"), 1);
+    assert_eq!(
+        Name::custom("A_B").unwrap_err().to_string(),
+        "invalid character name: unexpected byte at offset 1"
+    );
+}
+
+#[test]
+fn test_name_kind() {
+    let kind = |v: u32| v.char_name().unwrap().kind();
+    assert_eq!(kind('A' as u32), NameKind::Explicit);
+    assert_eq!(kind(0xAC00), NameKind::HangulSyllable);
+    assert_eq!(
+        kind(0x4E00),
+        NameKind::Nr2 {
+            prefix: "CJK UNIFIED IDEOGRAPH-"
+        }
+    );
+    assert_eq!(
+        kind(0x17000),
+        NameKind::Nr2 {
+            prefix: "TANGUT IDEOGRAPH-"
+        }
+    );
+    assert_eq!(
+        kind(0x0081),
+        NameKind::Label {
+            kind: LabelKind::Control
+        }
+    );
+    assert_eq!(
+        kind(0xE000),
+        NameKind::Label {
+            kind: LabelKind::PrivateUse
+        }
+    );
+    assert_eq!(
+        kind(0xD800),
+        NameKind::Label {
+            kind: LabelKind::Surrogate
+        }
+    );
+    assert_eq!(
+        kind(0xFDD0),
+        NameKind::Label {
+            kind: LabelKind::Noncharacter
+        }
+    );
+    assert_eq!(
+        kind(0x0378),
+        NameKind::Label {
+            kind: LabelKind::Reserved
+        }
+    );
+    assert_eq!(
+        0x4E00u32.property_name().unwrap().kind(),
+        NameKind::Nr2 {
+            prefix: "CJK UNIFIED IDEOGRAPH-"
+        }
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_name_serde() {
+    use unicode_charname::Name;
+
+    for &v in &[
+        0x41u32, 0x1180, 0xFA0E, 0xAC00, 0x4E00, 0x17000, 0x0081, 0xE000, 0x1029F,
+    ] {
+        let name = v.char_name().unwrap();
+        let json = serde_json::to_string(&name).unwrap();
+        assert_eq!(json, format!("\"{}\"", name));
+        let back: Name = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, name);
+        assert_eq!(back.to_string(), name.to_string());
+        assert_eq!(back.kind(), name.kind());
+    }
+    let back: Name = serde_json::from_str("\"control-0081\"").unwrap();
+    assert_eq!(back, "control-0081");
+    assert_eq!(format!("{:#}", back), "U+0081 control-0081");
+    let back: Name = serde_json::from_str("\"OX\"").unwrap();
+    assert_eq!(format!("{:#}", back), "U+1F402 OX");
+    assert_eq!(back.code_point(), Some(0x1F402));
+
+    for invalid in &[
+        "\"latin capital letter a\"",
+        "\"-A\"",
+        "\"A-\"",
+        "\"A  B\"",
+        "\"A--B\"",
+        "\"1A\"",
+        "\"A 1\"",
+        "\"<LATIN CAPITAL LETTER A>\"",
+        "\"<control-81>\"",
+        "\"<control-0081\"",
+        "\"<cjk-4E00>\"",
+        "\"Control-0081\"",
+        "\"<reserved-0378 (Greek and Coptic)>\"",
+        "\"control-0081 (Latin-1 Supplement)\"",
+    ] {
+        assert!(
+            serde_json::from_str::<Name>(invalid).is_err(),
+            "{}",
+            invalid
+        );
+    }
+    // valid names of no character come back without a code point.
+    for unknown in &[
+        "\"NOT A CHARACTER NAME\"",
+        "\"HANGUL SYLLABLE Q\"",
+        "\"CJK UNIFIED IDEOGRAPH-0041\"",
+        "\"TANGUT IDEOGRAPH-4E00\"",
+        "\"<control-0041>\"",
+        "\"<reserved-0041>\"",
+        "\"reserved-0378 (Basic Latin)\"",
+    ] {
+        let back: Name = serde_json::from_str(unknown).unwrap();
+        assert_eq!(back.code_point(), None, "{}", unknown);
+    }
+    let err = serde_json::from_str::<Name>("\"LATIN small\"").unwrap_err();
+    assert!(err.to_string().contains("offset 6"), "{}", err);
+}